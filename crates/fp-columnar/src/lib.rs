@@ -67,11 +67,12 @@
 use std::sync::{Arc, OnceLock};
 
 use fp_types::{
-    DType, DatetimeStringResolution, Interval, IntervalClosed, NullKind, Period, PeriodFreq,
-    Scalar, SparseDType, Timedelta, TimedeltaStringResolution, Timestamp, TypeError, cast_scalar,
-    cast_scalar_owned, common_dtype, infer_dtype, nanall, nanany, nanargmax, nanargmin, nancummax,
-    nancummin, nancumprod, nancumsum, nankurt, nanmax, nanmean, nanmedian, nanmin, nannunique,
-    nanprod, nanptp, nanquantile, nansem, nanskew, nanstd, nansum, nanvar,
+    DECIMAL128_MAX_PRECISION, DType, DatetimeStringResolution, Decimal, Interval, IntervalClosed,
+    NullKind, Period, PeriodFreq, Scalar, SparseDType, Timedelta, TimedeltaStringResolution,
+    Timestamp, TypeError, cast_scalar, cast_scalar_owned, common_dtype, decimal_operand_digits,
+    infer_dtype, nanall, nanany, nanargmax, nanargmin, nancummax, nancummin, nancumprod, nancumsum,
+    nankurt, nanmax, nanmean, nanmedian, nanmin, nannunique, nanprod, nanptp, nanquantile, nansem,
+    nanskew, nanstd, nansum, nanvar,
 };
 use rustc_hash::{FxHashMap, FxHashSet};
 use serde::{Deserialize, Serialize};
@@ -866,6 +867,93 @@ pub enum ColumnData {
     /// `i64::MIN` ordinals are NaT.
     Period(Vec<i64>, PeriodFreq),
     Interval(Vec<Interval>),
+    /// Sized numeric buffers at their native width, so a `uint8` flag column
+    /// costs one byte per row instead of eight.
+    Int8(Arc<[i8]>),
+    Int16(Arc<[i16]>),
+    Int32(Arc<[i32]>),
+    UInt8(Arc<[u8]>),
+    UInt16(Arc<[u16]>),
+    UInt32(Arc<[u32]>),
+    UInt64(Arc<[u64]>),
+    Float32(Arc<[f32]>),
+    /// `decimal128` unscaled values plus the column scale.
+    Decimal128(Vec<i128>, u8),
+    /// Arrow-style list layout: row `i` spans `values[offsets[i]..offsets[i + 1]]`,
+    /// so `offsets` has one more entry than there are rows. Missing rows span
+    /// nothing.
+    List {
+        offsets: Vec<usize>,
+        values: Vec<Scalar>,
+    },
+    /// One child buffer per field, in first-seen field order. Rows that are
+    /// missing, or lack a field, hold `Null` in that child.
+    Struct {
+        fields: Vec<(String, Vec<Scalar>)>,
+        len: usize,
+    },
+}
+
+/// Narrow an integer lane into a sized buffer. Values were range-checked by
+/// `cast_scalar_owned` on the way into the column, so the fallback only ever
+/// fills invalid (missing) positions.
+fn narrow_int_buffer<T: TryFrom<i64> + Default>(values: &[Scalar]) -> Arc<[T]> {
+    values
+        .iter()
+        .map(|v| match v {
+            Scalar::Int64(i) => T::try_from(*i).unwrap_or_default(),
+            Scalar::Bool(b) => T::try_from(i64::from(*b)).unwrap_or_default(),
+            _ => T::default(),
+        })
+        .collect()
+}
+
+fn narrow_buffer_to_scalars<T: Copy>(
+    data: &[T],
+    dtype: DType,
+    validity: &ValidityMask,
+    present: impl Fn(T) -> Scalar,
+) -> Vec<Scalar> {
+    data.iter()
+        .enumerate()
+        .map(|(i, v)| {
+            if validity.get(i) {
+                present(*v)
+            } else {
+                Scalar::missing_for_dtype(dtype)
+            }
+        })
+        .collect()
+}
+
+/// An immutable numeric column backing held by reference count, handed out
+/// by [`Column::shared_numeric_buffer`] so an exporter can keep the buffer
+/// alive without copying it. The `Vec` forms mirror the move-not-copy
+/// `LazyAllValid*Vec` backings.
+#[derive(Debug, Clone)]
+#[doc(hidden)]
+pub enum SharedNumericBuffer {
+    Int64(Arc<[i64]>),
+    Int64Vec(Arc<Vec<i64>>),
+    Float64(Arc<[f64]>),
+    Float64Vec(Arc<Vec<f64>>),
+}
+
+impl SharedNumericBuffer {
+    #[must_use]
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Int64(data) => data.len(),
+            Self::Int64Vec(data) => data.len(),
+            Self::Float64(data) => data.len(),
+            Self::Float64Vec(data) => data.len(),
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 }
 
 /// Exact witness for all-valid Int64 columns whose key at row `i` is
@@ -896,9 +984,31 @@ impl Int64DenseCycleWitness {
     }
 }
 
+/// An immutable typed buffer owned outside this crate that a chunked column
+/// reads in place, e.g. an Arrow `ScalarBuffer` decoded from an IPC file.
+/// Handing one over costs an `Arc` bump, never a copy of the elements.
+pub type ForeignBuffer<T> = Arc<dyn AsRef<[T]> + Send + Sync>;
+
+/// Backing buffer for one [`Int64Chunk`]: the historical `Arc<[i64]>`, or a
+/// [`ForeignBuffer`] read in place.
+#[derive(Clone)]
+enum Int64ChunkBuffer {
+    Shared(Arc<[i64]>),
+    Foreign(ForeignBuffer<i64>),
+}
+
+impl Int64ChunkBuffer {
+    fn as_slice(&self) -> &[i64] {
+        match self {
+            Self::Shared(data) => data,
+            Self::Foreign(data) => (**data).as_ref(),
+        }
+    }
+}
+
 #[derive(Clone)]
 struct Int64Chunk {
-    data: Arc<[i64]>,
+    data: Int64ChunkBuffer,
     start: usize,
     len: usize,
 }
@@ -906,11 +1016,28 @@ struct Int64Chunk {
 impl Int64Chunk {
     fn new(data: Arc<[i64]>, start: usize, len: usize) -> Self {
         debug_assert!(start.checked_add(len).is_some_and(|end| end <= data.len()));
-        Self { data, start, len }
+        Self {
+            data: Int64ChunkBuffer::Shared(data),
+            start,
+            len,
+        }
+    }
+
+    fn foreign(data: ForeignBuffer<i64>, start: usize, len: usize) -> Self {
+        debug_assert!(
+            start
+                .checked_add(len)
+                .is_some_and(|end| end <= (*data).as_ref().len())
+        );
+        Self {
+            data: Int64ChunkBuffer::Foreign(data),
+            start,
+            len,
+        }
     }
 
     fn as_slice(&self) -> &[i64] {
-        &self.data[self.start..self.start + self.len]
+        &self.data.as_slice()[self.start..self.start + self.len]
     }
 }
 
@@ -932,6 +1059,8 @@ impl Int64Chunk {
 enum Float64ChunkBuffer {
     Shared(Arc<[f64]>),
     Owned(Arc<Vec<f64>>),
+    /// Read in place from a buffer owned outside this crate; see [`ForeignBuffer`].
+    Foreign(ForeignBuffer<f64>),
 }
 
 impl Float64ChunkBuffer {
@@ -939,6 +1068,7 @@ impl Float64ChunkBuffer {
         match self {
             Self::Shared(data) => data,
             Self::Owned(data) => data.as_slice(),
+            Self::Foreign(data) => (**data).as_ref(),
         }
     }
 
@@ -952,7 +1082,7 @@ impl Float64ChunkBuffer {
     fn shared_arc(&self) -> Option<&Arc<[f64]>> {
         match self {
             Self::Shared(data) => Some(data),
-            Self::Owned(_) => None,
+            Self::Owned(_) | Self::Foreign(_) => None,
         }
     }
 }
@@ -989,6 +1119,20 @@ impl Float64Chunk {
         }
     }
 
+    /// Read a [`ForeignBuffer`] in place as a chunk.
+    fn foreign(data: ForeignBuffer<f64>, start: usize, len: usize) -> Self {
+        debug_assert!(
+            start
+                .checked_add(len)
+                .is_some_and(|end| end <= (*data).as_ref().len())
+        );
+        Self {
+            data: Float64ChunkBuffer::Foreign(data),
+            start,
+            len,
+        }
+    }
+
     fn as_slice(&self) -> &[f64] {
         &self.data.as_slice()[self.start..self.start + self.len]
     }
@@ -1599,6 +1743,14 @@ impl ColumnData {
             }
             DType::Null => Self::Float64(Arc::from(vec![0.0; values.len()])),
             DType::Sparse => Self::Utf8(vec![String::new(); values.len()]),
+            DType::List => {
+                let (offsets, values) = list_buffers(values);
+                Self::List { offsets, values }
+            }
+            DType::Struct => Self::Struct {
+                fields: struct_buffers(values),
+                len: values.len(),
+            },
             DType::Timedelta64 => {
                 let data: Vec<i64> = values
                     .iter()
@@ -1651,9 +1803,58 @@ impl ColumnData {
                     .collect();
                 Self::Interval(data)
             }
+            DType::Decimal { scale, .. } => Self::Decimal128(
+                values
+                    .iter()
+                    .map(|v| match v {
+                        // `Column::new` already rescaled exactly; this only
+                        // guards values built by the unchecked constructors.
+                        Scalar::Decimal(d) => d.round_to_scale(scale).map_or(0, |d| d.value),
+                        _ => 0,
+                    })
+                    .collect(),
+                scale,
+            ),
+            DType::Int8 => Self::Int8(narrow_int_buffer(values)),
+            DType::Int16 => Self::Int16(narrow_int_buffer(values)),
+            DType::Int32 => Self::Int32(narrow_int_buffer(values)),
+            DType::UInt8 => Self::UInt8(narrow_int_buffer(values)),
+            DType::UInt16 => Self::UInt16(narrow_int_buffer(values)),
+            DType::UInt32 => Self::UInt32(narrow_int_buffer(values)),
+            DType::UInt64 => Self::UInt64(narrow_int_buffer(values)),
+            DType::Float32 => Self::Float32(
+                values
+                    .iter()
+                    .map(|v| match v {
+                        // Already rounded to single precision by the cast.
+                        Scalar::Float64(f) => *f as f32,
+                        Scalar::Int64(i) => *i as f32,
+                        Scalar::Bool(b) => f32::from(u8::from(*b)),
+                        _ => 0.0,
+                    })
+                    .collect(),
+            ),
         }
     }
 
+    /// The sized dtype a narrow buffer stores, or `None` for the 64-bit,
+    /// string and temporal buffers (whose dtype the buffer alone cannot tell
+    /// apart from its nullable twin).
+    #[must_use]
+    pub fn narrow_dtype(&self) -> Option<DType> {
+        Some(match self {
+            Self::Int8(_) => DType::Int8,
+            Self::Int16(_) => DType::Int16,
+            Self::Int32(_) => DType::Int32,
+            Self::UInt8(_) => DType::UInt8,
+            Self::UInt16(_) => DType::UInt16,
+            Self::UInt32(_) => DType::UInt32,
+            Self::UInt64(_) => DType::UInt64,
+            Self::Float32(_) => DType::Float32,
+            _ => return None,
+        })
+    }
+
     /// Convert typed array back to `Vec<Scalar>`, respecting `ValidityMask`.
     #[must_use]
     pub fn to_scalars(&self, dtype: DType, validity: &ValidityMask) -> Vec<Scalar> {
@@ -1746,6 +1947,60 @@ impl ColumnData {
                     }
                 })
                 .collect(),
+            Self::Int8(data) => {
+                narrow_buffer_to_scalars(data, dtype, validity, |v| Scalar::Int64(v.into()))
+            }
+            Self::Int16(data) => {
+                narrow_buffer_to_scalars(data, dtype, validity, |v| Scalar::Int64(v.into()))
+            }
+            Self::Int32(data) => {
+                narrow_buffer_to_scalars(data, dtype, validity, |v| Scalar::Int64(v.into()))
+            }
+            Self::UInt8(data) => {
+                narrow_buffer_to_scalars(data, dtype, validity, |v| Scalar::Int64(v.into()))
+            }
+            Self::UInt16(data) => {
+                narrow_buffer_to_scalars(data, dtype, validity, |v| Scalar::Int64(v.into()))
+            }
+            Self::UInt32(data) => {
+                narrow_buffer_to_scalars(data, dtype, validity, |v| Scalar::Int64(v.into()))
+            }
+            // `Column::from_narrow_data` refuses values above `i64::MAX`, so
+            // the saturation never fires on a column-owned buffer.
+            Self::UInt64(data) => narrow_buffer_to_scalars(data, dtype, validity, |v| {
+                Scalar::Int64(i64::try_from(v).unwrap_or(i64::MAX))
+            }),
+            Self::Float32(data) => {
+                narrow_buffer_to_scalars(data, dtype, validity, |v| Scalar::Float64(f64::from(v)))
+            }
+            Self::Decimal128(data, scale) => narrow_buffer_to_scalars(data, dtype, validity, |v| {
+                Scalar::Decimal(Decimal::new(v, *scale))
+            }),
+            Self::List { offsets, values } => offsets
+                .windows(2)
+                .enumerate()
+                .map(|(i, span)| {
+                    if validity.get(i) {
+                        Scalar::List(values[span[0]..span[1]].to_vec())
+                    } else {
+                        Scalar::missing_for_dtype(dtype)
+                    }
+                })
+                .collect(),
+            Self::Struct { fields, len } => (0..*len)
+                .map(|i| {
+                    if validity.get(i) {
+                        Scalar::Struct(
+                            fields
+                                .iter()
+                                .map(|(name, child)| (name.clone(), child[i].clone()))
+                                .collect(),
+                        )
+                    } else {
+                        Scalar::missing_for_dtype(dtype)
+                    }
+                })
+                .collect(),
         }
     }
 
@@ -1760,6 +2015,17 @@ impl ColumnData {
             Self::Datetime64(d) => d.len(),
             Self::Period(d, _) => d.len(),
             Self::Interval(d) => d.len(),
+            Self::Int8(d) => d.len(),
+            Self::Int16(d) => d.len(),
+            Self::Int32(d) => d.len(),
+            Self::UInt8(d) => d.len(),
+            Self::UInt16(d) => d.len(),
+            Self::UInt32(d) => d.len(),
+            Self::UInt64(d) => d.len(),
+            Self::Float32(d) => d.len(),
+            Self::Decimal128(d, _) => d.len(),
+            Self::List { offsets, .. } => offsets.len().saturating_sub(1),
+            Self::Struct { len, .. } => *len,
         }
     }
 
@@ -1832,6 +2098,20 @@ fn scalar_compare(left: &Scalar, right: &Scalar, op: ComparisonOp) -> Result<boo
         });
     }
 
+    // Decimals compare exactly against decimals and integers; a float operand
+    // falls through to the f64 comparison below.
+    if let Some((a, b)) = decimal_operands(left, right) {
+        let ordering = a.cmp(&b);
+        return Ok(match op {
+            ComparisonOp::Gt => ordering.is_gt(),
+            ComparisonOp::Lt => ordering.is_lt(),
+            ComparisonOp::Eq => ordering.is_eq(),
+            ComparisonOp::Ne => ordering.is_ne(),
+            ComparisonOp::Ge => ordering.is_ge(),
+            ComparisonOp::Le => ordering.is_le(),
+        });
+    }
+
     // Numeric: convert both to f64.
     let lhs = left.to_f64()?;
     let rhs = right.to_f64()?;
@@ -1846,6 +2126,21 @@ fn scalar_compare(left: &Scalar, right: &Scalar, op: ComparisonOp) -> Result<boo
     })
 }
 
+/// Both operands as decimals when at least one is a decimal and the other
+/// converts exactly (a decimal, integer or bool).
+fn decimal_operands(left: &Scalar, right: &Scalar) -> Option<(Decimal, Decimal)> {
+    let exact = |value: &Scalar| match value {
+        Scalar::Decimal(d) => Some(*d),
+        Scalar::Int64(v) => Some(Decimal::new(i128::from(*v), 0)),
+        Scalar::Bool(v) => Some(Decimal::new(i128::from(*v), 0)),
+        _ => None,
+    };
+    if !left.is_decimal() && !right.is_decimal() {
+        return None;
+    }
+    Some((exact(left)?, exact(right)?))
+}
+
 /// AG-10: Vectorized binary arithmetic on `&[f64]` slices.
 ///
 /// Both inputs must have the same length. The combined validity mask
@@ -2433,6 +2728,13 @@ enum ScalarValues {
         freq: PeriodFreq,
         values: OnceLock<Vec<Scalar>>,
     },
+    /// All-valid sized numeric backing (`int8`..`uint64`, `float32`). The
+    /// narrow buffer is shared with `Column::data`, so until a caller asks for
+    /// the `Scalar` view the column holds only its native-width bytes.
+    LazyAllValidNarrow {
+        data: ColumnData,
+        values: OnceLock<Vec<Scalar>>,
+    },
     /// Nullable Datetime64 backing (the temporal mirror of `LazyNullableInt64`):
     /// contiguous ns `data` + `validity`; an invalid slot materializes
     /// `Scalar::Null(NullKind::NaT)` (= `missing_for_dtype(Datetime64)`), a valid
@@ -2982,6 +3284,14 @@ impl ScalarValues {
         }
     }
 
+    fn lazy_all_valid_narrow(data: ColumnData) -> Self {
+        debug_assert!(data.narrow_dtype().is_some());
+        Self::LazyAllValidNarrow {
+            data,
+            values: OnceLock::new(),
+        }
+    }
+
     fn lazy_all_valid_period_owned(data: Vec<i64>, freq: PeriodFreq) -> Self {
         Self::LazyAllValidPeriodVec {
             data: Arc::new(data),
@@ -4955,6 +5265,11 @@ impl ScalarValues {
             chunks, len, data, ..
         } = self
         {
+            // A lone chunk already is the contiguous buffer; borrowing it keeps a
+            // foreign (e.g. file-backed) chunk from being copied on first read.
+            if let [chunk] = &chunks[..] {
+                return Some(chunk.as_slice());
+            }
             return Some(
                 data.get_or_init(|| Self::materialize_float64_chunks(chunks, *len))
                     .as_slice(),
@@ -4998,6 +5313,9 @@ impl ScalarValues {
             chunks, len, data, ..
         } = self
         {
+            if let [chunk] = &chunks[..] {
+                return Some(chunk.as_slice());
+            }
             return Some(
                 data.get_or_init(|| Self::materialize_int64_chunks(chunks, *len))
                     .as_slice(),
@@ -5089,6 +5407,14 @@ impl ScalarValues {
             Self::LazyAllValidTimedelta64Vec { data, values } => values
                 .get_or_init(|| data.iter().copied().map(Scalar::Timedelta64).collect())
                 .as_slice(),
+            Self::LazyAllValidNarrow { data, values } => values
+                .get_or_init(|| {
+                    data.to_scalars(
+                        data.narrow_dtype().unwrap_or(DType::Null),
+                        &ValidityMask::all_valid(data.len()),
+                    )
+                })
+                .as_slice(),
             Self::LazyAllValidPeriodVec { data, freq, values } => values
                 .get_or_init(|| {
                     data.iter()
@@ -5872,6 +6198,7 @@ impl ScalarValues {
             Self::LazyAllValidDatetime64 { data, .. } => data.len(),
             Self::LazyAllValidDatetime64Vec { data, .. } => data.len(),
             Self::LazyAllValidTimedelta64Vec { data, .. } => data.len(),
+            Self::LazyAllValidNarrow { data, .. } => data.len(),
             Self::LazyAllValidPeriodVec { data, .. } => data.len(),
             Self::LazyNullableDatetime64 { data, .. } => data.len(),
             Self::LazyNullableTimedelta64 { data, .. } => data.len(),
@@ -6139,6 +6466,7 @@ impl Clone for ScalarValues {
                 data: Arc::clone(data),
                 values: OnceLock::new(),
             },
+            Self::LazyAllValidNarrow { data, .. } => Self::lazy_all_valid_narrow(data.clone()),
             Self::LazyAllValidPeriodVec { data, freq, .. } => Self::LazyAllValidPeriodVec {
                 data: Arc::clone(data),
                 freq: *freq,
@@ -10649,6 +10977,63 @@ fn normalized_float_bits(value: f64) -> u64 {
     normalized.to_bits()
 }
 
+/// Hash key for a decimal: trailing fractional zeros stripped, so `1.5` and
+/// `1.50` (equal values) share one key.
+fn decimal_key(decimal: &Decimal) -> (i128, u8) {
+    let normalized = decimal.normalized();
+    (normalized.value, normalized.scale)
+}
+
+/// Hash key for list/struct values. `Debug` spells out every element's
+/// variant, so `[1]` and `[1.0]` stay distinct the way pyarrow keeps them.
+fn nested_key(value: &Scalar) -> String {
+    format!("{value:?}")
+}
+
+/// Split `List` row values into an offsets buffer and the flat child values.
+fn list_buffers(values: &[Scalar]) -> (Vec<usize>, Vec<Scalar>) {
+    let mut offsets = Vec::with_capacity(values.len() + 1);
+    let mut flat = Vec::new();
+    offsets.push(0);
+    for value in values {
+        if let Scalar::List(items) = value {
+            flat.extend(items.iter().cloned());
+        }
+        offsets.push(flat.len());
+    }
+    (offsets, flat)
+}
+
+/// Split `Struct` row values into one child buffer per field name.
+fn struct_buffers(values: &[Scalar]) -> Vec<(String, Vec<Scalar>)> {
+    let mut names: Vec<&str> = Vec::new();
+    for value in values {
+        if let Scalar::Struct(pairs) = value {
+            for (name, _) in pairs {
+                if !names.contains(&name.as_str()) {
+                    names.push(name);
+                }
+            }
+        }
+    }
+    names
+        .into_iter()
+        .map(|name| {
+            let child = values
+                .iter()
+                .map(|value| match value {
+                    Scalar::Struct(pairs) => pairs
+                        .iter()
+                        .find(|(field, _)| field == name)
+                        .map_or(Scalar::Null(NullKind::Null), |(_, v)| v.clone()),
+                    _ => Scalar::Null(NullKind::Null),
+                })
+                .collect();
+            (name.to_owned(), child)
+        })
+        .collect()
+}
+
 fn interval_key(interval: &Interval) -> (u64, u64, IntervalClosed) {
     (
         normalized_float_bits(interval.left),
@@ -10675,6 +11060,8 @@ enum SetMemberKey<'a> {
     Datetime64(i64),
     Period(i64, PeriodFreq),
     Interval(u64, u64, IntervalClosed),
+    Decimal(i128, u8),
+    Nested(String),
 }
 
 fn set_member_key(v: &Scalar) -> Option<SetMemberKey<'_>> {
@@ -10693,6 +11080,11 @@ fn set_member_key(v: &Scalar) -> Option<SetMemberKey<'_>> {
             let (left, right, closed) = interval_key(v);
             SetMemberKey::Interval(left, right, closed)
         }
+        Scalar::Decimal(d) => {
+            let (value, scale) = decimal_key(d);
+            SetMemberKey::Decimal(value, scale)
+        }
+        nested @ (Scalar::List(_) | Scalar::Struct(_)) => SetMemberKey::Nested(nested_key(nested)),
         Scalar::Null(_) => return None,
     })
 }
@@ -10715,10 +11107,156 @@ pub enum ColumnError {
     DTypeMismatch { left: DType, right: DType },
     #[error("Integers to negative integer powers are not allowed.")]
     NegativeIntegerPower,
+    #[error("expected a sized numeric buffer (int8..uint64, float32)")]
+    NotNarrowData,
+    #[error("decimal128 {op:?}: {reason}")]
+    DecimalArithmetic {
+        op: ArithmeticOp,
+        reason: &'static str,
+    },
+    #[error("Can only use the .{accessor} accessor with a {accessor} dtype; found {dtype:?}")]
+    AccessorDType {
+        accessor: &'static str,
+        dtype: DType,
+    },
+    #[error("Index {index} is out of bounds for a list of length {len}")]
+    ListIndexOutOfBounds { index: i64, len: usize },
+    #[error("No struct field named {name:?}")]
+    MissingStructField { name: String },
     #[error(transparent)]
     Type(#[from] TypeError),
 }
 
+/// `Series.list` accessor over a `List` column's offsets buffer.
+#[derive(Debug, Clone)]
+pub struct ListAccessor<'a> {
+    validity: &'a ValidityMask,
+    offsets: std::borrow::Cow<'a, [usize]>,
+    values: std::borrow::Cow<'a, [Scalar]>,
+}
+
+impl ListAccessor<'_> {
+    fn span(&self, row: usize) -> &[Scalar] {
+        &self.values[self.offsets[row]..self.offsets[row + 1]]
+    }
+
+    /// Per-row list length; missing lists stay missing.
+    ///
+    /// Matches `pd.Series.list.len()`.
+    pub fn len(&self) -> Result<Column, ColumnError> {
+        let lengths = (0..self.validity.len())
+            .map(|row| {
+                if self.validity.get(row) {
+                    Scalar::Int64(self.span(row).len() as i64)
+                } else {
+                    Scalar::Null(NullKind::Null)
+                }
+            })
+            .collect();
+        Column::new(DType::Int64Nullable, lengths)
+    }
+
+    /// Element `index` of every list, counting from the end when negative.
+    ///
+    /// Matches `pd.Series.list[index]`: missing lists give missing, and an
+    /// index outside any present list is an error rather than a silent null.
+    pub fn get(&self, index: i64) -> Result<Column, ColumnError> {
+        let mut out = Vec::with_capacity(self.validity.len());
+        for row in 0..self.validity.len() {
+            if !self.validity.get(row) {
+                out.push(Scalar::Null(NullKind::Null));
+                continue;
+            }
+            let items = self.span(row);
+            let len = items.len();
+            let position = if index < 0 {
+                usize::try_from(index.unsigned_abs())
+                    .ok()
+                    .and_then(|back| len.checked_sub(back))
+            } else {
+                usize::try_from(index).ok().filter(|&i| i < len)
+            };
+            let Some(position) = position else {
+                return Err(ColumnError::ListIndexOutOfBounds { index, len });
+            };
+            out.push(items[position].clone());
+        }
+        Column::from_values(out)
+    }
+
+    /// Every element of every present list, in row order.
+    ///
+    /// Matches `pd.Series.list.flatten()`: missing and empty lists contribute
+    /// nothing.
+    pub fn flatten(&self) -> Result<Column, ColumnError> {
+        Column::from_values(self.values.to_vec())
+    }
+
+    /// One output row per list element, paired with its source row.
+    ///
+    /// Matches `pd.Series.explode()`: missing and empty lists keep a single
+    /// missing row so no source row disappears.
+    pub fn explode(&self) -> Result<(Vec<usize>, Column), ColumnError> {
+        let mut rows = Vec::with_capacity(self.values.len());
+        let mut out = Vec::with_capacity(self.values.len());
+        for row in 0..self.validity.len() {
+            let items = if self.validity.get(row) {
+                self.span(row)
+            } else {
+                &[]
+            };
+            if items.is_empty() {
+                rows.push(row);
+                out.push(Scalar::Null(NullKind::Null));
+            } else {
+                rows.extend(std::iter::repeat_n(row, items.len()));
+                out.extend(items.iter().cloned());
+            }
+        }
+        Ok((rows, Column::from_values(out)?))
+    }
+}
+
+/// `Series.struct` accessor over a `Struct` column's child buffers.
+#[derive(Debug, Clone)]
+pub struct StructAccessor<'a> {
+    fields: std::borrow::Cow<'a, [(String, Vec<Scalar>)]>,
+}
+
+impl StructAccessor<'_> {
+    /// Field names in column order.
+    ///
+    /// Matches `pd.Series.struct.dtypes.index`.
+    #[must_use]
+    pub fn field_names(&self) -> Vec<&str> {
+        self.fields.iter().map(|(name, _)| name.as_str()).collect()
+    }
+
+    /// One field as its own column; missing structs give missing.
+    ///
+    /// Matches `pd.Series.struct.field(name)`.
+    pub fn field(&self, name: &str) -> Result<Column, ColumnError> {
+        let (_, child) = self
+            .fields
+            .iter()
+            .find(|(field, _)| field == name)
+            .ok_or_else(|| ColumnError::MissingStructField {
+                name: name.to_owned(),
+            })?;
+        Column::from_values(child.clone())
+    }
+
+    /// Every field as a named column, in field order.
+    ///
+    /// Matches `pd.Series.struct.explode()`.
+    pub fn explode(&self) -> Result<Vec<(String, Column)>, ColumnError> {
+        self.fields
+            .iter()
+            .map(|(name, child)| Ok((name.clone(), Column::from_values(child.clone())?)))
+            .collect()
+    }
+}
+
 impl SparseColumn {
     pub fn from_dense(dtype: SparseDType, values: Vec<Scalar>) -> Result<Self, ColumnError> {
         let len = values.len();
@@ -10937,6 +11475,11 @@ impl Column {
                         .collect(),
                 ))
             }
+            (Some(data), dtype)
+                if data.narrow_dtype() == Some(dtype) && data.len() == self.values.len() =>
+            {
+                Some(ScalarValues::lazy_all_valid_narrow(data.clone()))
+            }
             _ => None,
         }
     }
@@ -10950,7 +11493,18 @@ impl Column {
             | DType::Float64
             | DType::Timedelta64
             | DType::Datetime64
-            | DType::Period => Some(ColumnData::from_scalars(values, dtype)),
+            | DType::Period
+            | DType::Int8
+            | DType::Int16
+            | DType::Int32
+            | DType::UInt8
+            | DType::UInt16
+            | DType::UInt32
+            | DType::UInt64
+            | DType::Float32
+            | DType::Decimal { .. }
+            | DType::List
+            | DType::Struct => Some(ColumnData::from_scalars(values, dtype)),
             _ => None,
         }
     }
@@ -11004,7 +11558,7 @@ impl Column {
                     // toward zero. astype pre-truncates via cast_scalar, so this
                     // coercion path only ever sees raw floats from the explicit
                     // constructor. (br-frankenpandas-8nupg)
-                    if matches!(dtype, DType::Int64 | DType::Int64Nullable)
+                    if dtype.is_integer()
                         && let Scalar::Float64(v) = &value
                         && v.is_finite()
                         && v.fract() != 0.0
@@ -11051,6 +11605,9 @@ impl Column {
             (Some(ColumnData::Int64(data)), DType::Int64, true) => {
                 ScalarValues::lazy_all_valid_int64_arc(Arc::clone(data))
             }
+            (Some(data), _, true) if data.narrow_dtype().is_some() => {
+                ScalarValues::lazy_all_valid_narrow(data.clone())
+            }
             _ => ScalarValues::from_vec(coerced),
         };
 
@@ -11202,6 +11759,46 @@ impl Column {
         }
     }
 
+    /// Build a sized numeric column (`int8`..`uint64`, `float32`) directly from
+    /// its native-width buffer, as the columnar readers do.
+    ///
+    /// Invalid slots read as the dtype's missing value; their buffer contents
+    /// are ignored. An all-valid column keeps only the narrow buffer until a
+    /// caller materializes `Scalar`s. `uint64` values above `i64::MAX` are
+    /// refused, since `Scalar::Int64` carries every integer lane.
+    pub fn from_narrow_data(data: ColumnData, validity: ValidityMask) -> Result<Self, ColumnError> {
+        let dtype = data.narrow_dtype().ok_or(ColumnError::NotNarrowData)?;
+        if validity.len() != data.len() {
+            return Err(ColumnError::LengthMismatch {
+                left: data.len(),
+                right: validity.len(),
+            });
+        }
+        if let ColumnData::UInt64(buffer) = &data
+            && let Some(value) = buffer
+                .iter()
+                .enumerate()
+                .find_map(|(i, v)| (validity.get(i) && i64::try_from(*v).is_err()).then_some(*v))
+        {
+            return Err(TypeError::ValueOutOfRange {
+                value: value.to_string(),
+                dtype,
+            }
+            .into());
+        }
+        let values = if validity.all() {
+            ScalarValues::lazy_all_valid_narrow(data.clone())
+        } else {
+            ScalarValues::from_vec(data.to_scalars(dtype, &validity))
+        };
+        Ok(Self {
+            dtype,
+            values,
+            validity,
+            data: Some(data),
+        })
+    }
+
     /// Build an all-valid Int64 column from immutable chunks that are already
     /// in output row order. Semantically identical to concatenating the chunks
     /// into one `Vec<i64>` and calling [`Self::from_i64_values`], but defers
@@ -11228,6 +11825,34 @@ impl Column {
         }
     }
 
+    /// As [`Self::from_i64_all_valid_chunks`], but reading each chunk in place
+    /// from a [`ForeignBuffer`] instead of an `Arc<[i64]>`, so a buffer decoded
+    /// elsewhere (an Arrow IPC record batch) becomes a column without a copy.
+    #[doc(hidden)]
+    #[must_use]
+    pub fn from_i64_all_valid_foreign_chunks(
+        chunks: Vec<(ForeignBuffer<i64>, usize, usize)>,
+        len: usize,
+    ) -> Self {
+        debug_assert_eq!(
+            chunks
+                .iter()
+                .map(|(_, _, chunk_len)| *chunk_len)
+                .sum::<usize>(),
+            len
+        );
+        let chunks: Vec<Int64Chunk> = chunks
+            .into_iter()
+            .map(|(data, start, chunk_len)| Int64Chunk::foreign(data, start, chunk_len))
+            .collect();
+        Self {
+            dtype: DType::Int64,
+            values: ScalarValues::lazy_all_valid_int64_chunks(Arc::from(chunks), len),
+            validity: ValidityMask::all_valid(len),
+            data: None,
+        }
+    }
+
     /// Build an all-valid `Datetime64` column from a `Vec<i64>` of nanosecond
     /// epochs (br-frankenpandas-j5150). Caller guarantees no `Timestamp::NAT`
     /// sentinel (all rows present); semantically identical to
@@ -11950,6 +12575,33 @@ impl Column {
         }
     }
 
+    /// As [`Self::from_f64_all_valid_chunks`], but reading each chunk in place
+    /// from a [`ForeignBuffer`]. The same non-NaN precondition applies.
+    #[doc(hidden)]
+    #[must_use]
+    pub fn from_f64_all_valid_foreign_chunks(
+        chunks: Vec<(ForeignBuffer<f64>, usize, usize)>,
+        len: usize,
+    ) -> Self {
+        debug_assert_eq!(
+            chunks
+                .iter()
+                .map(|(_, _, chunk_len)| *chunk_len)
+                .sum::<usize>(),
+            len
+        );
+        let chunks: Vec<Float64Chunk> = chunks
+            .into_iter()
+            .map(|(data, start, chunk_len)| Float64Chunk::foreign(data, start, chunk_len))
+            .collect();
+        Self {
+            dtype: DType::Float64,
+            values: ScalarValues::lazy_all_valid_float64_chunks(Arc::from(chunks), len),
+            validity: ValidityMask::all_valid(len),
+            data: None,
+        }
+    }
+
     /// As [`Self::from_f64_all_valid_chunks`], but taking each chunk's buffer by
     /// pointer move instead of by copy.
     ///
@@ -12597,6 +13249,110 @@ impl Column {
         Some((data, &self.validity))
     }
 
+    /// The native-width buffer of a sized numeric column (`int8`..`uint64`,
+    /// `float32`). Rows invalid in [`Self::validity`] hold unspecified values.
+    #[must_use]
+    pub fn narrow_data(&self) -> Option<&ColumnData> {
+        self.data
+            .as_ref()
+            .filter(|data| data.narrow_dtype() == Some(self.dtype) && data.len() == self.len())
+    }
+
+    /// The `.list` accessor of a `List` column. Reads the cached offsets
+    /// buffer, rebuilding it when the cache was dropped.
+    pub fn list(&self) -> Result<ListAccessor<'_>, ColumnError> {
+        if self.dtype != DType::List {
+            return Err(ColumnError::AccessorDType {
+                accessor: "list",
+                dtype: self.dtype,
+            });
+        }
+        let (offsets, values) = match &self.data {
+            Some(ColumnData::List { offsets, values }) if offsets.len() == self.len() + 1 => (
+                std::borrow::Cow::Borrowed(offsets.as_slice()),
+                std::borrow::Cow::Borrowed(values.as_slice()),
+            ),
+            _ => {
+                let (offsets, values) = list_buffers(self.values());
+                (
+                    std::borrow::Cow::Owned(offsets),
+                    std::borrow::Cow::Owned(values),
+                )
+            }
+        };
+        Ok(ListAccessor {
+            validity: &self.validity,
+            offsets,
+            values,
+        })
+    }
+
+    /// The `.struct` accessor of a `Struct` column. Reads the cached child
+    /// buffers, rebuilding them when the cache was dropped.
+    pub fn r#struct(&self) -> Result<StructAccessor<'_>, ColumnError> {
+        if self.dtype != DType::Struct {
+            return Err(ColumnError::AccessorDType {
+                accessor: "struct",
+                dtype: self.dtype,
+            });
+        }
+        let fields = match &self.data {
+            Some(ColumnData::Struct { fields, len }) if *len == self.len() => {
+                std::borrow::Cow::Borrowed(fields.as_slice())
+            }
+            _ => std::borrow::Cow::Owned(struct_buffers(self.values())),
+        };
+        Ok(StructAccessor { fields })
+    }
+
+    /// Return an Int64/Float64 column's shared contiguous backing and row start
+    /// offset, whatever its validity (rows invalid in [`Self::validity`] hold
+    /// unspecified values). `None` for backings that are not already shared —
+    /// chunk tapes, owned nullable `Vec` storage and lazy descriptors — so the
+    /// caller decides whether a copy is acceptable.
+    #[must_use]
+    #[doc(hidden)]
+    pub fn shared_numeric_buffer(&self) -> Option<(SharedNumericBuffer, usize)> {
+        let (buffer, start) = match self.dtype {
+            DType::Int64 | DType::Int64Nullable => match &self.values {
+                ScalarValues::LazyAllValidInt64 { data, .. } => {
+                    (SharedNumericBuffer::Int64(Arc::clone(data)), 0)
+                }
+                ScalarValues::LazyAllValidInt64Vec { data, .. } => {
+                    (SharedNumericBuffer::Int64Vec(Arc::clone(data)), 0)
+                }
+                _ => match &self.data {
+                    Some(ColumnData::Int64(data)) => {
+                        (SharedNumericBuffer::Int64(Arc::clone(data)), 0)
+                    }
+                    _ => return None,
+                },
+            },
+            DType::Float64 | DType::Float64Nullable => match &self.values {
+                ScalarValues::LazyAllValidFloat64 { data, .. } => {
+                    (SharedNumericBuffer::Float64(Arc::clone(data)), 0)
+                }
+                ScalarValues::LazyAllValidFloat64Vec { data, .. } => {
+                    (SharedNumericBuffer::Float64Vec(Arc::clone(data)), 0)
+                }
+                ScalarValues::LazyAllValidFloat64Slice { data, start, .. } => {
+                    (SharedNumericBuffer::Float64(Arc::clone(data)), *start)
+                }
+                _ => match &self.data {
+                    Some(ColumnData::Float64(data)) => {
+                        (SharedNumericBuffer::Float64(Arc::clone(data)), 0)
+                    }
+                    _ => return None,
+                },
+            },
+            _ => return None,
+        };
+        if start + self.validity.len() > buffer.len() {
+            return None;
+        }
+        Some((buffer, start))
+    }
+
     #[must_use]
     #[doc(hidden)]
     pub fn shared_f64_data_with_validity(&self) -> Option<(Arc<[f64]>, &ValidityMask)> {
@@ -13861,7 +14617,8 @@ impl Column {
             return Self::from_i64_values_with_validity(
                 gathered,
                 ValidityMask::from_words(words, n),
-            );
+            )
+            .keep_masked_dtype(self.dtype);
         }
 
         // Nullable temporal / Bool siblings of the LazyNullableInt64 gather above.
@@ -13915,7 +14672,8 @@ impl Column {
             return Self::from_bool_values_with_validity(
                 gathered,
                 ValidityMask::from_words(words, n),
-            );
+            )
+            .keep_masked_dtype(self.dtype);
         }
 
         // Nullable contiguous-Utf8 sibling of the typed nullable gathers above: a
@@ -14858,6 +15616,79 @@ impl Column {
         }
     }
 
+    /// Re-tag a typed gather's result with its source's masked dtype.
+    ///
+    /// The nullable Int64/Bool gathers build through
+    /// `from_{i64,bool}_values_with_validity`, which always tag plain
+    /// `Int64`/`Bool`. An `Int64Nullable`/`BoolNullable` source has to come back
+    /// masked — pandas never widens a masked column on a take, filter or
+    /// reindex, and the plain tag is what later let a left join's invented
+    /// gaps turn an ID column into floats. Both pairs share the `Null(Null)`
+    /// missing value, so only the tag changes.
+    fn keep_masked_dtype(mut self, source: DType) -> Self {
+        if matches!(
+            (source, self.dtype),
+            (DType::Int64Nullable, DType::Int64) | (DType::BoolNullable, DType::Bool)
+        ) {
+            self.dtype = source;
+        }
+        self
+    }
+
+    /// Convert to the best masked dtype, matching `pd.Series.convert_dtypes()`.
+    ///
+    /// `Int64` becomes `Int64Nullable` and `Bool` becomes `BoolNullable`. A
+    /// float column whose present values are all whole numbers in `i64` range
+    /// becomes `Int64Nullable`, and any other float column becomes
+    /// `Float64Nullable`; NaN turns into `<NA>` either way. Every other dtype
+    /// is returned unchanged. The masked result keeps its dtype through later
+    /// gathers, joins and integer arithmetic instead of widening to `Float64`.
+    pub fn convert_dtypes(&self) -> Result<Self, ColumnError> {
+        match self.dtype {
+            DType::Int64 | DType::Bool => Ok(Self {
+                dtype: self.dtype.to_nullable(),
+                values: self.values.clone(),
+                validity: self.validity.clone(),
+                data: self.data.clone(),
+            }),
+            DType::Float64 | DType::Float64Nullable => {
+                let mut ints = Vec::with_capacity(self.len());
+                let mut validity = ValidityMask::all_valid(self.len());
+                let mut integral = true;
+                for (idx, value) in self.values.iter().enumerate() {
+                    if value.is_missing() {
+                        ints.push(0);
+                        validity.set(idx, false);
+                        continue;
+                    }
+                    let v = value.to_f64()?;
+                    if v.fract() != 0.0 || !(-(2.0_f64.powi(63))..2.0_f64.powi(63)).contains(&v) {
+                        integral = false;
+                        break;
+                    }
+                    ints.push(v as i64);
+                }
+                if integral {
+                    return Ok(Self::from_i64_values_with_validity(ints, validity)
+                        .keep_masked_dtype(DType::Int64Nullable));
+                }
+                let values = self
+                    .values
+                    .iter()
+                    .map(|value| {
+                        if value.is_missing() {
+                            Scalar::Null(NullKind::Null)
+                        } else {
+                            value.clone()
+                        }
+                    })
+                    .collect();
+                Self::new(DType::Float64Nullable, values)
+            }
+            _ => Ok(self.clone()),
+        }
+    }
+
     /// Create a new column with a different dtype, preserving the same values.
     ///
     /// This is a low-level operation that only changes the dtype metadata
@@ -15278,7 +16109,8 @@ impl Column {
             return Ok(Self::from_i64_values_with_validity(
                 out,
                 ValidityMask::from_words(words, n),
-            ));
+            )
+            .keep_masked_dtype(self.dtype));
         }
         // Nullable Bool source null-introducing gather (sibling of the nullable-Int64 arm).
         // Bool has NO sentinel value — every missing slot (validity-clear, None, or
@@ -15303,7 +16135,8 @@ impl Column {
             return Ok(Self::from_bool_values_with_validity(
                 out,
                 ValidityMask::from_words(words, n),
-            ));
+            )
+            .keep_masked_dtype(self.dtype));
         }
         // NOTE (negative evidence, 2026-07-14 cc_fp): a nullable-TEMPORAL sibling here is a
         // NullKind TRAP — the generic gather fills None/out-of-range with
@@ -16193,6 +17026,18 @@ impl Column {
             });
         }
 
+        // Decimal arithmetic against decimals and integers is exact. A float
+        // operand skips this and computes in f64 through the Float64Nullable
+        // promotion, as pyarrow does.
+        if (self.dtype.is_decimal() || right.dtype.is_decimal())
+            && let (Some(left_digits), Some(right_digits)) = (
+                decimal_operand_digits(self.dtype),
+                decimal_operand_digits(right.dtype),
+            )
+        {
+            return self.binary_decimal(right, op, left_digits, right_digits);
+        }
+
         let mut out_dtype = common_dtype(self.dtype, right.dtype)?;
         if matches!(out_dtype, DType::Bool) {
             out_dtype = DType::Int64;
@@ -16200,9 +17045,10 @@ impl Column {
         // Div always produces Float64. Pow keeps Int64 for int**int (numpy/pandas
         // semantics: 2 ** 3 -> int64 8, not float), but promotes to Float64 for any
         // float operand. Mod and FloorDiv preserve int if there are no zero divisors.
-        let int_pow = matches!(op, ArithmeticOp::Pow)
-            && self.dtype == DType::Int64
-            && right.dtype == DType::Int64;
+        // The masked Int64Nullable counts as an integer here too: pandas keeps
+        // `Int64 ** Int64` as `Int64`.
+        let is_int = |dtype: DType| matches!(dtype, DType::Int64 | DType::Int64Nullable);
+        let int_pow = matches!(op, ArithmeticOp::Pow) && is_int(self.dtype) && is_int(right.dtype);
         if matches!(op, ArithmeticOp::Div | ArithmeticOp::Pow) && !int_pow {
             out_dtype = DType::Float64;
         }
@@ -16245,7 +17091,18 @@ impl Column {
                     });
                 }
 
-                if matches!(out_dtype, DType::Int64) {
+                // Int64Nullable add/sub/mul/pow stays exact in i64 rather than
+                // round-tripping through f64, which loses IDs above 2**53.
+                if matches!(out_dtype, DType::Int64)
+                    || (matches!(out_dtype, DType::Int64Nullable)
+                        && matches!(
+                            op,
+                            ArithmeticOp::Add
+                                | ArithmeticOp::Sub
+                                | ArithmeticOp::Mul
+                                | ArithmeticOp::Pow
+                        ))
+                {
                     let lhs_i64 = match cast_scalar(left, DType::Int64)? {
                         Scalar::Int64(v) => v,
                         _ => unreachable!(),
@@ -16293,6 +17150,81 @@ impl Column {
         Self::new(out_dtype, values)
     }
 
+    /// Exact `decimal128` arithmetic for [`Self::binary_numeric`].
+    ///
+    /// Operands are `(integer digits, scale)`. Result types follow Arrow, with
+    /// precision capped at 38:
+    /// - add/sub: scale `max(s1, s2)`, precision `max(p1 - s1, p2 - s2) + scale + 1`
+    /// - mul: scale `s1 + s2`, precision `p1 + p2 + 1`
+    /// - div: scale `max(4, s1 + p2 - s2 + 1)`, precision `p1 - s1 + s2 + scale`,
+    ///   rounded half-to-even
+    ///
+    /// Overflow and division by zero are errors rather than a wrong value; a
+    /// result wider than the capped precision fails the column cast the same
+    /// way. Mod, pow and floordiv are not defined for decimals.
+    fn binary_decimal(
+        &self,
+        right: &Self,
+        op: ArithmeticOp,
+        (left_digits, left_scale): (u8, u8),
+        (right_digits, right_scale): (u8, u8),
+    ) -> Result<Self, ColumnError> {
+        let fail = |reason| ColumnError::DecimalArithmetic { op, reason };
+        let (scale, precision) = match op {
+            ArithmeticOp::Add | ArithmeticOp::Sub => {
+                let scale = left_scale.max(right_scale);
+                (scale, left_digits.max(right_digits) + scale + 1)
+            }
+            ArithmeticOp::Mul => (
+                left_scale + right_scale,
+                left_digits + left_scale + right_digits + right_scale + 1,
+            ),
+            ArithmeticOp::Div => {
+                let scale = (left_scale + right_digits + 1).clamp(4, DECIMAL128_MAX_PRECISION);
+                (scale, left_digits + right_scale + scale)
+            }
+            ArithmeticOp::Mod | ArithmeticOp::Pow | ArithmeticOp::FloorDiv => {
+                return Err(fail("not supported for decimal128"));
+            }
+        };
+        if scale > DECIMAL128_MAX_PRECISION {
+            return Err(fail("result scale exceeds 38 digits"));
+        }
+        let out_dtype = DType::Decimal {
+            precision: precision.min(DECIMAL128_MAX_PRECISION),
+            scale,
+        };
+
+        let values = self
+            .values
+            .iter()
+            .zip(&right.values)
+            .map(|(left, right)| {
+                if left.is_missing() || right.is_missing() {
+                    return Ok(Scalar::missing_for_dtype(out_dtype));
+                }
+                let (lhs, rhs) =
+                    decimal_operands(left, right).ok_or(ColumnError::DTypeMismatch {
+                        left: left.dtype(),
+                        right: right.dtype(),
+                    })?;
+                let result = match op {
+                    ArithmeticOp::Add => lhs.checked_add(rhs),
+                    ArithmeticOp::Sub => lhs.checked_sub(rhs),
+                    ArithmeticOp::Mul => lhs.checked_mul(rhs),
+                    ArithmeticOp::Div if rhs.value == 0 => return Err(fail("divide by zero")),
+                    ArithmeticOp::Div => lhs.checked_div(rhs, scale),
+                    ArithmeticOp::Mod | ArithmeticOp::Pow | ArithmeticOp::FloorDiv => {
+                        unreachable!()
+                    }
+                };
+                result.map(Scalar::Decimal).ok_or_else(|| fail("overflow"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::new(out_dtype, values)
+    }
+
     /// Element-wise addition, matching `pd.Series.add()`.
     pub fn add(&self, right: &Self) -> Result<Self, ColumnError> {
         self.binary_numeric(right, ArithmeticOp::Add)
@@ -16829,6 +17761,15 @@ impl Column {
                 right: other.len(),
             });
         }
+        if self.is_kleene_pair(other) {
+            return Ok(
+                self.kleene_logical(other, |left, right| match (left, right) {
+                    (Some(false), _) | (_, Some(false)) => Some(false),
+                    (Some(true), Some(true)) => Some(true),
+                    _ => None,
+                }),
+            );
+        }
         if let Some(out) = self.typed_bool_binary(other, |left, right| left && right) {
             return Ok(out);
         }
@@ -16864,6 +17805,15 @@ impl Column {
                 right: other.len(),
             });
         }
+        if self.is_kleene_pair(other) {
+            return Ok(
+                self.kleene_logical(other, |left, right| match (left, right) {
+                    (Some(true), _) | (_, Some(true)) => Some(true),
+                    (Some(false), Some(false)) => Some(false),
+                    _ => None,
+                }),
+            );
+        }
         if let Some(out) = self.typed_bool_binary(other, |left, right| left || right) {
             return Ok(out);
         }
@@ -16899,6 +17849,9 @@ impl Column {
                 right: other.len(),
             });
         }
+        if self.is_kleene_pair(other) {
+            return Ok(self.kleene_logical(other, |left, right| Some(left? ^ right?)));
+        }
         if let Some(out) = self.typed_bool_binary(other, |left, right| left ^ right) {
             return Ok(out);
         }
@@ -17344,6 +18297,37 @@ impl Column {
         Some(Self::from_bool_values_with_validity(bools, out_valid))
     }
 
+    /// Three-valued logic for the masked `boolean` dtype. Taken when either
+    /// operand is `BoolNullable`; the result is `BoolNullable`. `op` sees
+    /// `None` for `<NA>` and answers `None` where the outcome is unknown, so
+    /// `False & NA` is `False` and `True | NA` is `True` as in pandas.
+    fn kleene_logical<F>(&self, other: &Self, op: F) -> Self
+    where
+        F: Fn(Option<bool>, Option<bool>) -> Option<bool>,
+    {
+        let truth = |value: &Scalar| match value {
+            _ if value.is_missing() => None,
+            Scalar::Bool(x) => Some(*x),
+            _ => Some(value.to_f64().map(|v| v != 0.0).unwrap_or(false)),
+        };
+        let mut bools = Vec::with_capacity(self.len());
+        let mut validity = ValidityMask::all_valid(self.len());
+        for (idx, (a, b)) in self.values.iter().zip(&other.values).enumerate() {
+            match op(truth(a), truth(b)) {
+                Some(x) => bools.push(x),
+                None => {
+                    bools.push(false);
+                    validity.set(idx, false);
+                }
+            }
+        }
+        Self::from_bool_values_with_validity(bools, validity).keep_masked_dtype(DType::BoolNullable)
+    }
+
+    fn is_kleene_pair(&self, other: &Self) -> bool {
+        self.dtype == DType::BoolNullable || other.dtype == DType::BoolNullable
+    }
+
     /// Logical AND between two boolean columns.
     pub fn logical_and(&self, other: &Self) -> Result<Self, ColumnError> {
         if self.len() != other.len() {
@@ -17352,6 +18336,15 @@ impl Column {
                 right: other.len(),
             });
         }
+        if self.is_kleene_pair(other) {
+            return Ok(
+                self.kleene_logical(other, |left, right| match (left, right) {
+                    (Some(false), _) | (_, Some(false)) => Some(false),
+                    (Some(true), Some(true)) => Some(true),
+                    _ => None,
+                }),
+            );
+        }
         if let Some(out) = self.typed_bool_binary(other, |left, right| left && right) {
             return Ok(out);
         }
@@ -17385,6 +18378,15 @@ impl Column {
                 right: other.len(),
             });
         }
+        if self.is_kleene_pair(other) {
+            return Ok(
+                self.kleene_logical(other, |left, right| match (left, right) {
+                    (Some(true), _) | (_, Some(true)) => Some(true),
+                    (Some(false), Some(false)) => Some(false),
+                    _ => None,
+                }),
+            );
+        }
         if let Some(out) = self.typed_bool_binary(other, |left, right| left || right) {
             return Ok(out);
         }
@@ -17418,6 +18420,9 @@ impl Column {
                 right: other.len(),
             });
         }
+        if self.is_kleene_pair(other) {
+            return Ok(self.kleene_logical(other, |left, right| Some(left? ^ right?)));
+        }
         if let Some(out) = self.typed_bool_binary(other, |left, right| left ^ right) {
             return Ok(out);
         }
@@ -17493,6 +18498,10 @@ impl Column {
             };
             out.push(Scalar::Bool(!bv));
         }
+        // `~` on the masked `boolean` dtype keeps `<NA>` and stays masked.
+        if self.dtype == DType::BoolNullable {
+            return Self::new(DType::BoolNullable, out);
+        }
         Self::new(DType::Bool, out)
     }
 
@@ -18193,7 +19202,8 @@ impl Column {
                 return Ok(Self::from_i64_values_with_validity(
                     gathered,
                     ValidityMask::from_words(words, count),
-                ));
+                )
+                .keep_masked_dtype(self.dtype));
             }
             // Nullable temporal / Bool siblings (mirror the take_positions gathers):
             // Datetime64/Timedelta64 store i64 ns (missing ⇒ Null(NaT)), Bool raw
@@ -18258,7 +19268,8 @@ impl Column {
                 return Ok(Self::from_bool_values_with_validity(
                     gathered,
                     ValidityMask::from_words(words, count),
-                ));
+                )
+                .keep_masked_dtype(self.dtype));
             }
             let values = self
                 .values
@@ -21402,6 +22413,8 @@ impl Column {
             Datetime64(i64),
             Period(i64),
             Interval(u64, u64, IntervalClosed),
+            Decimal(i128, u8),
+            Nested(String),
         }
         let mut seen: FxHashSet<Key<'_>> = FxHashSet::default();
         for v in &self.values {
@@ -21423,6 +22436,11 @@ impl Column {
                     let (left, right, closed) = interval_key(v);
                     Key::Interval(left, right, closed)
                 }
+                Scalar::Decimal(d) => {
+                    let (value, scale) = decimal_key(d);
+                    Key::Decimal(value, scale)
+                }
+                nested @ (Scalar::List(_) | Scalar::Struct(_)) => Key::Nested(nested_key(nested)),
                 Scalar::Null(_) => continue,
             };
             if !seen.insert(key) {
@@ -22512,6 +23530,8 @@ impl Column {
             Datetime64(i64),
             Period(i64),
             Interval(u64, u64, IntervalClosed),
+            Decimal(i128, u8),
+            Nested(String),
         }
         fn key_of(v: &Scalar) -> Option<Key<'_>> {
             if v.is_missing() {
@@ -22532,6 +23552,11 @@ impl Column {
                     let (left, right, closed) = interval_key(v);
                     Key::Interval(left, right, closed)
                 }
+                Scalar::Decimal(d) => {
+                    let (value, scale) = decimal_key(d);
+                    Key::Decimal(value, scale)
+                }
+                nested @ (Scalar::List(_) | Scalar::Struct(_)) => Key::Nested(nested_key(nested)),
                 Scalar::Null(_) => return None,
             })
         }
@@ -22575,6 +23600,7 @@ impl Column {
         let element_bytes = match self.dtype {
             DType::Bool => 1,
             DType::Int64 | DType::Float64 | DType::Timedelta64 => 8,
+            dtype if dtype.is_narrow_numeric() || dtype.is_decimal() => dtype.itemsize(),
             DType::Utf8 => std::mem::size_of::<usize>(),
             _ => 0,
         };
@@ -22609,6 +23635,15 @@ impl Column {
     pub fn itemsize(&self) -> usize {
         match self.dtype() {
             DType::Bool | DType::BoolNullable => 1,
+            dtype @ (DType::Int8
+            | DType::Int16
+            | DType::Int32
+            | DType::UInt8
+            | DType::UInt16
+            | DType::UInt32
+            | DType::UInt64
+            | DType::Float32
+            | DType::Decimal { .. }) => dtype.itemsize(),
             DType::Int64
             | DType::Int64Nullable
             | DType::Float64
@@ -22623,7 +23658,12 @@ impl Column {
                     self.memory_usage(true) / self.values.len()
                 }
             }
-            DType::Null | DType::Categorical | DType::Interval | DType::Sparse => 8,
+            DType::Null
+            | DType::Categorical
+            | DType::Interval
+            | DType::Sparse
+            | DType::List
+            | DType::Struct => 8,
         }
     }
 
@@ -23259,6 +24299,9 @@ impl Column {
                 Scalar::Datetime64(x) => *x != Timestamp::NAT,
                 Scalar::Period(p) => p.ordinal != i64::MIN,
                 Scalar::Interval(_) => true,
+                Scalar::Decimal(d) => d.value != 0,
+                Scalar::List(items) => !items.is_empty(),
+                Scalar::Struct(fields) => !fields.is_empty(),
                 Scalar::Null(_) => false,
             };
             if truthy {
@@ -25778,6 +26821,8 @@ impl Column {
             Datetime64(i64),
             Period(i64),
             Interval(u64, u64, IntervalClosed),
+            Decimal(i128, u8),
+            Nested(String),
         }
         fn key_of(v: &Scalar) -> Key<'_> {
             if v.is_missing() {
@@ -25798,6 +26843,11 @@ impl Column {
                     let (left, right, closed) = interval_key(v);
                     Key::Interval(left, right, closed)
                 }
+                Scalar::Decimal(d) => {
+                    let (value, scale) = decimal_key(d);
+                    Key::Decimal(value, scale)
+                }
+                nested @ (Scalar::List(_) | Scalar::Struct(_)) => Key::Nested(nested_key(nested)),
                 Scalar::Null(_) => Key::Null,
             }
         }
@@ -26240,7 +27290,7 @@ impl Column {
         // use_na_sentinel=false branch separately so multiple null
         // kinds collapse to the same code (matches the existing
         // is_missing-based check).
-        #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+        #[derive(Hash, PartialEq, Eq, Clone)]
         enum LocalKey<'a> {
             Bool(bool),
             Int64(i64),
@@ -26250,6 +27300,8 @@ impl Column {
             Datetime64(i64),
             Period(i64),
             Interval(u64, u64, IntervalClosed),
+            Decimal(i128, u8),
+            Nested(String),
         }
         fn key_of(s: &Scalar) -> Option<LocalKey<'_>> {
             match s {
@@ -26290,6 +27342,13 @@ impl Column {
                     let (left, right, closed) = interval_key(interval);
                     Some(LocalKey::Interval(left, right, closed))
                 }
+                Scalar::Decimal(d) => {
+                    let (value, scale) = decimal_key(d);
+                    Some(LocalKey::Decimal(value, scale))
+                }
+                nested @ (Scalar::List(_) | Scalar::Struct(_)) => {
+                    Some(LocalKey::Nested(nested_key(nested)))
+                }
             }
         }
 
@@ -29655,6 +30714,8 @@ impl Column {
             Datetime64(i64),
             Period(i64),
             Interval(u64, u64, IntervalClosed),
+            Decimal(i128, u8),
+            Nested(String),
         }
         fn key_of(v: &Scalar) -> Option<Key<'_>> {
             if v.is_missing() {
@@ -29675,6 +30736,11 @@ impl Column {
                     let (left, right, closed) = interval_key(v);
                     Key::Interval(left, right, closed)
                 }
+                Scalar::Decimal(d) => {
+                    let (value, scale) = decimal_key(d);
+                    Key::Decimal(value, scale)
+                }
+                nested @ (Scalar::List(_) | Scalar::Struct(_)) => Key::Nested(nested_key(nested)),
                 Scalar::Null(_) => return None,
             })
         }
@@ -30012,6 +31078,8 @@ impl Column {
             Datetime64(i64),
             Period(i64),
             Interval(u64, u64, IntervalClosed),
+            Decimal(i128, u8),
+            Nested(String),
         }
 
         let mut seen: FxHashSet<Key<'_>> = FxHashSet::default();
@@ -30035,6 +31103,11 @@ impl Column {
                     let (left, right, closed) = interval_key(v);
                     Key::Interval(left, right, closed)
                 }
+                Scalar::Decimal(d) => {
+                    let (value, scale) = decimal_key(d);
+                    Key::Decimal(value, scale)
+                }
+                nested @ (Scalar::List(_) | Scalar::Struct(_)) => Key::Nested(nested_key(nested)),
                 Scalar::Null(_) => continue,
             };
             if seen.insert(key) {
@@ -31057,6 +32130,236 @@ mod tests {
         SparseColumn, ValidityMask,
     };
 
+    #[test]
+    fn decimal_columns_compute_exactly() {
+        use fp_types::Decimal;
+        let dec = |text: &str| Scalar::Decimal(Decimal::parse(text).expect("decimal literal"));
+        let money = DType::Decimal {
+            precision: 10,
+            scale: 2,
+        };
+
+        let prices = Column::new(
+            money,
+            vec![dec("0.1"), dec("19.99"), Scalar::Null(NullKind::Null)],
+        )
+        .expect("decimal column");
+        assert_eq!(
+            prices.data,
+            Some(ColumnData::Decimal128(vec![10, 1999, 0], 2))
+        );
+        assert_eq!(prices.itemsize(), 16);
+        let fees = Column::new(money, vec![dec("0.20"), dec("0.01"), dec("1")]).expect("fees");
+
+        let total = prices.add(&fees).expect("add");
+        assert_eq!(
+            total.dtype(),
+            DType::Decimal {
+                precision: 11,
+                scale: 2
+            }
+        );
+        assert_eq!(
+            total.values(),
+            &[dec("0.30"), dec("20.00"), Scalar::Null(NullKind::Null)]
+        );
+        assert_eq!(total.sum(), dec("20.30"));
+
+        let quantity =
+            Column::from_values(vec![Scalar::Int64(3), Scalar::Int64(2), Scalar::Int64(1)])
+                .expect("int column");
+        let extended = prices.mul(&quantity).expect("mul");
+        assert_eq!(extended.values()[..2], [dec("0.30"), dec("39.98")]);
+
+        let thirds = Column::new(money, vec![dec("2.00")])
+            .expect("numerator")
+            .div(&Column::new(money, vec![dec("3.00")]).expect("denominator"))
+            .expect("div");
+        assert_eq!(
+            thirds.dtype(),
+            DType::Decimal {
+                precision: 21,
+                scale: 11
+            }
+        );
+        assert_eq!(thirds.values(), &[dec("0.66666666667")]);
+        assert_eq!(
+            fees.div(&Column::new(money, vec![dec("0"); 3]).expect("zeros")),
+            Err(ColumnError::DecimalArithmetic {
+                op: ArithmeticOp::Div,
+                reason: "divide by zero"
+            })
+        );
+
+        let cheaper = prices.lt(&fees).expect("compare");
+        assert_eq!(
+            cheaper.values()[..2],
+            [Scalar::Bool(true), Scalar::Bool(false)]
+        );
+        let as_float = prices
+            .add(&Column::from_values(vec![Scalar::Float64(0.5); 3]).expect("floats"))
+            .expect("float add");
+        assert_eq!(as_float.dtype(), DType::Float64Nullable);
+    }
+
+    #[test]
+    fn list_and_struct_accessors_read_offsets_storage() {
+        let list = |items: &[i64]| Scalar::List(items.iter().copied().map(Scalar::Int64).collect());
+        let tags = Column::from_values(vec![
+            list(&[1, 2, 3]),
+            Scalar::Null(NullKind::Null),
+            list(&[]),
+            list(&[4]),
+        ])
+        .expect("list column");
+        assert_eq!(tags.dtype(), DType::List);
+        assert_eq!(
+            tags.data,
+            Some(ColumnData::List {
+                offsets: vec![0, 3, 3, 3, 4],
+                values: [1, 2, 3, 4].into_iter().map(Scalar::Int64).collect(),
+            })
+        );
+
+        let accessor = tags.list().expect("list accessor");
+        assert_eq!(
+            accessor.len().expect("len").values(),
+            &[
+                Scalar::Int64(3),
+                Scalar::Null(NullKind::Null),
+                Scalar::Int64(0),
+                Scalar::Int64(1)
+            ]
+        );
+        assert_eq!(
+            accessor.get(0),
+            Err(ColumnError::ListIndexOutOfBounds { index: 0, len: 0 })
+        );
+        let firsts = Column::new(DType::List, vec![list(&[1, 2]), list(&[7])])
+            .expect("non-empty lists")
+            .list()
+            .expect("accessor")
+            .get(-1)
+            .expect("last element");
+        assert_eq!(firsts.values(), &[Scalar::Int64(2), Scalar::Int64(7)]);
+        assert_eq!(
+            accessor.flatten().expect("flatten").values(),
+            &[1, 2, 3, 4].map(Scalar::Int64)
+        );
+        let (rows, exploded) = accessor.explode().expect("explode");
+        assert_eq!(rows, vec![0, 0, 0, 1, 2, 3]);
+        assert_eq!(exploded.len(), 6);
+        assert!(exploded.values()[3].is_missing() && exploded.values()[4].is_missing());
+
+        let points = Column::from_values(vec![
+            Scalar::Struct(vec![
+                ("x".to_owned(), Scalar::Int64(1)),
+                ("label".to_owned(), Scalar::Utf8("a".to_owned())),
+            ]),
+            Scalar::Null(NullKind::Null),
+            Scalar::Struct(vec![("x".to_owned(), Scalar::Int64(5))]),
+        ])
+        .expect("struct column");
+        assert_eq!(points.dtype(), DType::Struct);
+        let fields = points.r#struct().expect("struct accessor");
+        assert_eq!(fields.field_names(), vec!["x", "label"]);
+        let x = fields.field("x").expect("x field");
+        assert!(x.values()[0].semantic_eq(&Scalar::Int64(1)));
+        assert!(x.values()[1].is_missing());
+        let exploded = fields.explode().expect("explode");
+        assert_eq!(exploded[1].0, "label");
+        assert!(exploded[1].1.values()[2].is_missing());
+        assert_eq!(
+            fields.field("y"),
+            Err(ColumnError::MissingStructField {
+                name: "y".to_owned()
+            })
+        );
+        assert!(matches!(
+            x.list(),
+            Err(ColumnError::AccessorDType {
+                accessor: "list",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn narrow_numeric_columns_keep_native_width_buffers() {
+        let flags = Column::new(
+            DType::UInt8,
+            vec![Scalar::Int64(0), Scalar::Int64(255), Scalar::Bool(true)],
+        )
+        .expect("uint8 column");
+        assert_eq!(
+            flags.data,
+            Some(ColumnData::UInt8(Arc::from(vec![0_u8, 255, 1])))
+        );
+        assert!(matches!(
+            flags.values,
+            ScalarValues::LazyAllValidNarrow { .. }
+        ));
+        assert_eq!(flags.itemsize(), 1);
+        assert_eq!(flags.nbytes(), 3 + 1);
+        assert_eq!(
+            flags.values(),
+            &[Scalar::Int64(0), Scalar::Int64(255), Scalar::Int64(1)]
+        );
+        let cloned = flags.clone();
+        assert_eq!(cloned.values(), flags.values());
+
+        assert!(matches!(
+            Column::new(DType::Int8, vec![Scalar::Int64(200)]),
+            Err(ColumnError::Type(
+                fp_types::TypeError::ValueOutOfRange { .. }
+            ))
+        ));
+        assert!(matches!(
+            Column::new(DType::Int16, vec![Scalar::Float64(1.5)]),
+            Err(ColumnError::Type(
+                fp_types::TypeError::LossyFloatToInt { .. }
+            ))
+        ));
+
+        let halves = Column::new(
+            DType::Float32,
+            vec![Scalar::Float64(0.1), Scalar::Null(NullKind::Null)],
+        )
+        .expect("float32 column");
+        assert_eq!(halves.values()[0], Scalar::Float64(f64::from(0.1_f32)));
+        assert_eq!(halves.values()[1], Scalar::Null(NullKind::NaN));
+
+        let mut validity = ValidityMask::all_valid(3);
+        validity.set(1, false);
+        let read = Column::from_narrow_data(ColumnData::Int32(Arc::from(vec![7, 0, -9])), validity)
+            .expect("int32 buffer");
+        assert_eq!(read.dtype(), DType::Int32);
+        assert_eq!(
+            read.values(),
+            &[
+                Scalar::Int64(7),
+                Scalar::Null(NullKind::Null),
+                Scalar::Int64(-9)
+            ]
+        );
+        assert!(matches!(
+            Column::from_narrow_data(
+                ColumnData::UInt64(Arc::from(vec![u64::MAX])),
+                ValidityMask::all_valid(1)
+            ),
+            Err(ColumnError::Type(
+                fp_types::TypeError::ValueOutOfRange { .. }
+            ))
+        ));
+        assert!(matches!(
+            Column::from_narrow_data(
+                ColumnData::Int64(Arc::from(vec![1])),
+                ValidityMask::all_valid(1)
+            ),
+            Err(ColumnError::NotNarrowData)
+        ));
+    }
+
     #[test]
     #[ignore = "foreground profile-first A/B"]
     fn trapz_raw_float64_profile_gc20b() {
@@ -34448,6 +35751,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn foreign_chunks_are_read_in_place() {
+        let ints: Arc<Vec<i64>> = Arc::new(vec![7, 1, 2, 3]);
+        let floats: Arc<Vec<f64>> = Arc::new(vec![0.5, 1.5, 2.5]);
+        let int_column = Column::from_i64_all_valid_foreign_chunks(
+            vec![(Arc::clone(&ints) as crate::ForeignBuffer<i64>, 1, 3)],
+            3,
+        );
+        let float_column = Column::from_f64_all_valid_foreign_chunks(
+            vec![
+                (Arc::clone(&floats) as crate::ForeignBuffer<f64>, 0, 2),
+                (Arc::clone(&floats) as crate::ForeignBuffer<f64>, 2, 1),
+            ],
+            3,
+        );
+
+        // A single chunk is borrowed, not copied into a fresh buffer.
+        let ints_slice = int_column.as_i64_slice().expect("typed view");
+        assert_eq!(ints_slice, [1, 2, 3].as_slice());
+        assert!(std::ptr::eq(ints_slice.as_ptr(), ints[1..].as_ptr()));
+        assert_eq!(
+            float_column.as_f64_slice(),
+            Some([0.5, 1.5, 2.5].as_slice())
+        );
+        assert_eq!(
+            float_column.values(),
+            &[
+                Scalar::Float64(0.5),
+                Scalar::Float64(1.5),
+                Scalar::Float64(2.5),
+            ]
+        );
+        assert_eq!(
+            int_column.clone(),
+            Column::from_i64_values_owned(vec![1, 2, 3])
+        );
+    }
+
     #[test]
     fn from_f64_all_valid_dot_product_materializes_left_fold_rows() {
         let a0: Arc<[f64]> = Arc::from(vec![99.0, 1.0, 3.0, 5.0]);
@@ -54320,6 +55661,170 @@ mod tests {
         assert_eq!(changed.values()[0], Scalar::Int64(42));
     }
 
+    #[test]
+    fn masked_int64_and_bool_stay_masked_through_gathers() {
+        let ints = Column::from_i64_values_with_validity(
+            vec![10, 0, 30],
+            ValidityMask::from_values(&[
+                Scalar::Int64(10),
+                Scalar::Null(NullKind::Null),
+                Scalar::Int64(30),
+            ]),
+        )
+        .promote_to_nullable();
+        assert!(matches!(
+            ints.values,
+            ScalarValues::LazyNullableInt64 { .. }
+        ));
+        let bools = Column::from_bool_values_with_validity(
+            vec![true, false, false],
+            ValidityMask::from_values(&[
+                Scalar::Bool(true),
+                Scalar::Null(NullKind::Null),
+                Scalar::Bool(false),
+            ]),
+        )
+        .promote_to_nullable();
+        let keep = Column::from_values(vec![
+            Scalar::Bool(true),
+            Scalar::Bool(true),
+            Scalar::Bool(false),
+        ])
+        .unwrap();
+
+        for (column, masked) in [(&ints, DType::Int64Nullable), (&bools, DType::BoolNullable)] {
+            assert_eq!(column.dtype(), masked);
+            let taken = column.take_positions(&[2, 1, 0]);
+            assert_eq!(taken.dtype(), masked);
+            assert_eq!(taken.values()[1], Scalar::Null(NullKind::Null));
+            let reindexed = column.reindex_by_positions(&[Some(0), None]).unwrap();
+            assert_eq!(reindexed.dtype(), masked);
+            assert_eq!(reindexed.values()[1], Scalar::Null(NullKind::Null));
+            assert_eq!(column.filter_by_mask(&keep).unwrap().dtype(), masked);
+        }
+    }
+
+    #[test]
+    fn masked_int64_arithmetic_is_exact_and_stays_masked() {
+        let big = 9_007_199_254_740_993_i64;
+        let left = Column::new(
+            DType::Int64Nullable,
+            vec![
+                Scalar::Int64(big),
+                Scalar::Null(NullKind::Null),
+                Scalar::Int64(3),
+            ],
+        )
+        .unwrap();
+        let right = Column::new(DType::Int64, vec![Scalar::Int64(2); 3]).unwrap();
+
+        let sum = left.add(&right).unwrap();
+        assert_eq!(sum.dtype(), DType::Int64Nullable);
+        assert_eq!(
+            sum.values(),
+            &[
+                Scalar::Int64(big + 2),
+                Scalar::Null(NullKind::Null),
+                Scalar::Int64(5),
+            ]
+        );
+        let squared = left.binary_numeric(&right, ArithmeticOp::Pow).unwrap();
+        assert_eq!(squared.dtype(), DType::Int64Nullable);
+        assert_eq!(squared.values()[2], Scalar::Int64(9));
+    }
+
+    #[test]
+    fn masked_bool_logic_is_kleene() {
+        let na = Scalar::Null(NullKind::Null);
+        let left = Column::new(
+            DType::BoolNullable,
+            vec![
+                Scalar::Bool(false),
+                Scalar::Bool(true),
+                na.clone(),
+                Scalar::Bool(true),
+            ],
+        )
+        .unwrap();
+        let right = Column::new(
+            DType::BoolNullable,
+            vec![na.clone(), na.clone(), na.clone(), Scalar::Bool(false)],
+        )
+        .unwrap();
+
+        let and = left.bitwise_and(&right).unwrap();
+        assert_eq!(and.dtype(), DType::BoolNullable);
+        assert_eq!(
+            and.values(),
+            &[
+                Scalar::Bool(false),
+                na.clone(),
+                na.clone(),
+                Scalar::Bool(false)
+            ]
+        );
+        let or = left.logical_or(&right).unwrap();
+        assert_eq!(or.dtype(), DType::BoolNullable);
+        assert_eq!(
+            or.values(),
+            &[
+                na.clone(),
+                Scalar::Bool(true),
+                na.clone(),
+                Scalar::Bool(true)
+            ]
+        );
+        let xor = left.bitwise_xor(&right).unwrap();
+        assert_eq!(
+            xor.values(),
+            &[na.clone(), na.clone(), na.clone(), Scalar::Bool(true)]
+        );
+        let not = left.logical_not().unwrap();
+        assert_eq!(not.dtype(), DType::BoolNullable);
+        assert_eq!(not.values()[2], na);
+
+        // numpy bool keeps its two-valued behaviour.
+        let plain = Column::from_values(vec![Scalar::Bool(false), Scalar::Bool(true)]).unwrap();
+        assert_eq!(plain.logical_and(&plain).unwrap().dtype(), DType::Bool);
+    }
+
+    #[test]
+    fn convert_dtypes_picks_masked_dtypes() {
+        let ints = Column::from_values(vec![Scalar::Int64(1), Scalar::Int64(2)]).unwrap();
+        assert_eq!(ints.convert_dtypes().unwrap().dtype(), DType::Int64Nullable);
+
+        let whole = Column::from_values(vec![
+            Scalar::Float64(1.0),
+            Scalar::Null(NullKind::NaN),
+            Scalar::Float64(-4.0),
+        ])
+        .unwrap()
+        .convert_dtypes()
+        .unwrap();
+        assert_eq!(whole.dtype(), DType::Int64Nullable);
+        assert_eq!(
+            whole.values(),
+            &[
+                Scalar::Int64(1),
+                Scalar::Null(NullKind::Null),
+                Scalar::Int64(-4)
+            ]
+        );
+
+        let fractional =
+            Column::from_values(vec![Scalar::Float64(1.5), Scalar::Null(NullKind::NaN)])
+                .unwrap()
+                .convert_dtypes()
+                .unwrap();
+        assert_eq!(fractional.dtype(), DType::Float64Nullable);
+        assert_eq!(fractional.values()[1], Scalar::Null(NullKind::Null));
+
+        let flags = Column::from_values(vec![Scalar::Bool(true)]).unwrap();
+        assert_eq!(flags.convert_dtypes().unwrap().dtype(), DType::BoolNullable);
+        let text = Column::from_values(vec![Scalar::Utf8("a".to_owned())]).unwrap();
+        assert_eq!(text.convert_dtypes().unwrap().dtype(), DType::Utf8);
+    }
+
     #[test]
     fn nullable_int64_from_scalars_preserves_storage() {
         use super::ColumnData;
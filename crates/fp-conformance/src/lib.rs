@@ -6827,7 +6827,25 @@ fn fuzz_feather_scalar_for_dtype(dtype: DType, bytes: &[u8]) -> Scalar {
 
     match dtype {
         DType::Bool | DType::BoolNullable => Scalar::Bool(payload % 2 == 1),
-        DType::Int64 | DType::Int64Nullable => Scalar::Int64(i64::from(payload % 11) - 5),
+        DType::Int64 | DType::Int64Nullable | DType::Int8 | DType::Int16 | DType::Int32 => {
+            Scalar::Int64(i64::from(payload % 11) - 5)
+        }
+        DType::UInt8 | DType::UInt16 | DType::UInt32 | DType::UInt64 => {
+            Scalar::Int64(i64::from(payload % 11))
+        }
+        DType::Float32 => Scalar::Float64(f64::from(payload % 8) * 0.5),
+        DType::Decimal { scale, .. } => {
+            Scalar::Decimal(fp_types::Decimal::new(i128::from(payload % 10), scale))
+        }
+        DType::List => Scalar::List(
+            (0..payload % 3)
+                .map(|item| Scalar::Int64(i64::from(item)))
+                .collect(),
+        ),
+        DType::Struct => Scalar::Struct(vec![(
+            "value".to_owned(),
+            Scalar::Int64(i64::from(payload % 11)),
+        )]),
         DType::Float64 | DType::Float64Nullable => Scalar::Float64(match payload % 6 {
             0 => 0.0,
             1 => 1.0,
@@ -8041,8 +8059,10 @@ fn fuzz_expected_column_arith_dtype(
     }
     // Per br-frankenpandas-3w0xn: Int64 ** Int64 stays Int64 (numpy/pandas:
     // int**int -> int64, with negative integer exponents raising in the kernel
-    // before this oracle runs). Any float operand promotes Pow to Float64.
-    if matches!(op, ArithmeticOp::Pow) && !matches!(out_dtype, DType::Int64) {
+    // before this oracle runs), and the masked Int64Nullable stays masked. Any
+    // float operand promotes Pow to Float64.
+    if matches!(op, ArithmeticOp::Pow) && !matches!(out_dtype, DType::Int64 | DType::Int64Nullable)
+    {
         out_dtype = DType::Float64;
     }
     if matches!(op, ArithmeticOp::Mod | ArithmeticOp::FloorDiv) && matches!(out_dtype, DType::Int64)
@@ -15136,11 +15156,17 @@ fn parse_constructor_dtype_spec(dtype_spec: &str) -> Result<DType, String> {
         "boolean" => Ok(DType::BoolNullable),
         "int64" | "int" | "i64" => Ok(DType::Int64),
         "float64" | "float" | "f64" => Ok(DType::Float64),
+        "int8" => Ok(DType::Int8),
+        "int16" => Ok(DType::Int16),
+        "int32" => Ok(DType::Int32),
+        "uint8" => Ok(DType::UInt8),
+        "uint16" => Ok(DType::UInt16),
+        "uint32" => Ok(DType::UInt32),
+        "uint64" => Ok(DType::UInt64),
+        "float32" => Ok(DType::Float32),
         "utf8" | "string" | "str" => Ok(DType::Utf8),
-        _ => Err(format!(
-            "unsupported constructor dtype '{}'",
-            dtype_spec.trim()
-        )),
+        other => fp_types::parse_decimal_dtype(other)
+            .ok_or_else(|| format!("unsupported constructor dtype '{}'", dtype_spec.trim())),
     }
 }
 
@@ -17894,6 +17920,8 @@ fn encode_groupby_composite_key(values: &[Scalar]) -> Result<String, String> {
                 format!("pd:{}:{}", v.freq, v.ordinal)
             }
             Scalar::Interval(iv) => format!("iv:{iv}"),
+            Scalar::Decimal(d) => format!("dec:{}", d.normalized()),
+            nested @ (Scalar::List(_) | Scalar::Struct(_)) => format!("nested:{nested:?}"),
             Scalar::Null(_) => {
                 return Err("groupby composite key component cannot be null".to_owned());
             }
@@ -228,6 +228,17 @@ fn dtype_to_pandas_jsonl_name(dtype: DType) -> &'static str {
         // nullable lane's payload is the same f64. (br-frankenpandas-qkqfb)
         DType::Float64 | DType::Float64Nullable => "float64",
         DType::Int64 | DType::Int64Nullable => "int64",
+        DType::Int8 => "int8",
+        DType::Int16 => "int16",
+        DType::Int32 => "int32",
+        DType::UInt8 => "uint8",
+        DType::UInt16 => "uint16",
+        DType::UInt32 => "uint32",
+        DType::UInt64 => "uint64",
+        DType::Float32 => "float32",
+        DType::Decimal { .. } => "object",
+        DType::List => "list",
+        DType::Struct => "struct",
         DType::Utf8 | DType::Categorical | DType::Sparse | DType::Timedelta64 => "object",
         DType::Datetime64 => "datetime64[ns]",
         DType::Period => "period",
@@ -255,6 +266,10 @@ fn scalar_to_jsonl_cell(value: &Scalar) -> Value {
         Scalar::Datetime64(ns) => serde_json::json!({"kind": "datetime64", "value": ns}),
         Scalar::Period(p) => serde_json::json!({"kind": "period", "value": p.ordinal}),
         Scalar::Interval(iv) => serde_json::json!({"kind": "interval", "value": iv.to_string()}),
+        Scalar::Decimal(d) => serde_json::json!({"kind": "utf8", "value": d.to_string()}),
+        nested @ (Scalar::List(_) | Scalar::Struct(_)) => {
+            serde_json::json!({"kind": "utf8", "value": nested.to_string()})
+        }
     }
 }
 
@@ -218,6 +218,19 @@ fn poison_numeric_scalar(value: &Scalar) -> Scalar {
         Scalar::Datetime64(v) => Scalar::Datetime64(v.saturating_add(1)),
         Scalar::Period(v) => Scalar::Period(v.shift(1)),
         Scalar::Interval(iv) => Scalar::Interval(*iv),
+        Scalar::Decimal(d) => {
+            Scalar::Decimal(fp_types::Decimal::new(d.value.saturating_add(1), d.scale))
+        }
+        Scalar::List(items) => {
+            let mut items = items.clone();
+            items.push(Scalar::Int64(1));
+            Scalar::List(items)
+        }
+        Scalar::Struct(fields) => {
+            let mut fields = fields.clone();
+            fields.push(("poisoned".to_owned(), Scalar::Int64(1)));
+            Scalar::Struct(fields)
+        }
     }
 }
 
@@ -3449,7 +3462,7 @@ proptest! {
 /// Every `DType` FrankenPandas has. Kept exhaustive by
 /// `arb_dtype_covers_every_dtype_nv8az`, which will not COMPILE if a variant is
 /// added without being listed here. (br-frankenpandas-nv8az)
-const ALL_DTYPES: [fp_types::DType; 14] = [
+const ALL_DTYPES: [fp_types::DType; 25] = [
     fp_types::DType::Null,
     fp_types::DType::Bool,
     fp_types::DType::BoolNullable,
@@ -3464,6 +3477,20 @@ const ALL_DTYPES: [fp_types::DType; 14] = [
     fp_types::DType::Period,
     fp_types::DType::Interval,
     fp_types::DType::Sparse,
+    fp_types::DType::Int8,
+    fp_types::DType::Int16,
+    fp_types::DType::Int32,
+    fp_types::DType::UInt8,
+    fp_types::DType::UInt16,
+    fp_types::DType::UInt32,
+    fp_types::DType::UInt64,
+    fp_types::DType::Float32,
+    fp_types::DType::Decimal {
+        precision: 18,
+        scale: 2,
+    },
+    fp_types::DType::List,
+    fp_types::DType::Struct,
 ];
 
 /// Generate an arbitrary DType.
@@ -12394,6 +12421,17 @@ mod dtype_generator_exhaustiveness_nv8az {
             DType::Period => "Period",
             DType::Interval => "Interval",
             DType::Sparse => "Sparse",
+            DType::Int8 => "Int8",
+            DType::Int16 => "Int16",
+            DType::Int32 => "Int32",
+            DType::UInt8 => "UInt8",
+            DType::UInt16 => "UInt16",
+            DType::UInt32 => "UInt32",
+            DType::UInt64 => "UInt64",
+            DType::Float32 => "Float32",
+            DType::Decimal { .. } => "Decimal",
+            DType::List => "List",
+            DType::Struct => "Struct",
         }
     }
 
@@ -12412,7 +12450,7 @@ mod dtype_generator_exhaustiveness_nv8az {
         // A count assertion alone would be satisfied by listing one variant
         // fourteen times; the dedup above is what makes this meaningful, and
         // the match above is what makes it exhaustive.
-        assert_eq!(total, 14, "ALL_DTYPES must carry every DType variant");
+        assert_eq!(total, 25, "ALL_DTYPES must carry every DType variant");
     }
 
     /// The list being right proves nothing if the STRATEGY does not read it.
@@ -12433,8 +12471,8 @@ mod dtype_generator_exhaustiveness_nv8az {
         let mut runner = TestRunner::deterministic();
         let strategy = super::arb_dtype();
         let mut drawn: std::collections::BTreeSet<&'static str> = std::collections::BTreeSet::new();
-        // 2000 draws over 14 uniform variants: the chance of missing any one is
-        // 14 * (13/14)^2000, which is far below one in a billion, so a miss here
+        // 2000 draws over 22 uniform variants: the chance of missing any one is
+        // 22 * (21/22)^2000, which is far below one in a billion, so a miss here
         // means the generator genuinely cannot produce it.
         for _ in 0..2000 {
             let tree = strategy
@@ -12450,7 +12488,7 @@ mod dtype_generator_exhaustiveness_nv8az {
             "arb_dtype() never drew {missing:?} in 2000 attempts — every property \
              fed by it is blind to those dtypes"
         );
-        assert_eq!(drawn.len(), 14);
+        assert_eq!(drawn.len(), 22);
     }
 
     /// The nullable extension dtypes are the specific ones whose absence
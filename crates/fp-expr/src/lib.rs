@@ -334,6 +334,20 @@ pub enum Expr {
     },
 }
 
+impl Expr {
+    /// Names of every column (`Expr::Series`) the expression reads.
+    ///
+    /// IO readers use this to decode predicate columns that a projection would
+    /// otherwise leave out.
+    #[must_use]
+    pub fn referenced_series(&self) -> std::collections::BTreeSet<String> {
+        let mut series = std::collections::BTreeSet::new();
+        let mut locals = std::collections::BTreeSet::new();
+        MaterializedView::extract_bindings(self, &mut series, &mut locals);
+        series
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct EvalContext {
     series: BTreeMap<String, Series>,
@@ -2283,6 +2297,14 @@ fn parse_dtype_alias(value: &str) -> Result<DType, ExprError> {
         "bool" | "boolean" | "?" => Ok(DType::Bool),
         "int" | "integer" | "int64" | "i8" => Ok(DType::Int64),
         "float" | "floating" | "float64" | "f8" => Ok(DType::Float64),
+        "int8" | "i1" => Ok(DType::Int8),
+        "int16" | "i2" => Ok(DType::Int16),
+        "int32" | "i4" => Ok(DType::Int32),
+        "uint8" | "u1" => Ok(DType::UInt8),
+        "uint16" | "u2" => Ok(DType::UInt16),
+        "uint32" | "u4" => Ok(DType::UInt32),
+        "uint64" | "u8" => Ok(DType::UInt64),
+        "float32" | "f4" => Ok(DType::Float32),
         "object" | "string" | "str" | "utf8" | "o" => Ok(DType::Utf8),
         "category" | "categorical" => Ok(DType::Categorical),
         "timedelta" | "timedelta64" | "timedelta64[ns]" | "m8" | "m8[ns]" => Ok(DType::Timedelta64),
@@ -2290,9 +2312,11 @@ fn parse_dtype_alias(value: &str) -> Result<DType, ExprError> {
         "period" => Ok(DType::Period),
         "interval" => Ok(DType::Interval),
         "sparse" => Ok(DType::Sparse),
-        other => Err(ExprError::ParseError(format!(
-            "astype() dtype is not supported in expressions: {other:?}"
-        ))),
+        other => fp_types::parse_decimal_dtype(other).ok_or_else(|| {
+            ExprError::ParseError(format!(
+                "astype() dtype is not supported in expressions: {other:?}"
+            ))
+        }),
     }
 }
 
@@ -4707,6 +4731,13 @@ mod tests {
         .expect("series")
     }
 
+    #[test]
+    fn referenced_series_collects_every_column_once() {
+        let expr = super::parse_expr("a > 1 and (b < c or a == 3)").expect("parse");
+        let names: Vec<String> = expr.referenced_series().into_iter().collect();
+        assert_eq!(names, vec!["a", "b", "c"]);
+    }
+
     #[test]
     fn materialized_view_from_full_eval() {
         let a = make_series("a", vec![0, 1], vec![Scalar::Int64(10), Scalar::Int64(20)]);
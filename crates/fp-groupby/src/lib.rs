@@ -302,6 +302,7 @@ fn groupby_sum_with_trace(
     } else {
         groupby_sum_with_global_allocator(aligned_keys_values, aligned_values_values, options)?
     };
+    let result = keep_masked_result(result, values.column().dtype(), AggFunc::Sum)?;
 
     Ok((
         result,
@@ -513,6 +514,8 @@ fn emit_groupby_result<'a>(
             Scalar::Datetime64(v) => IndexLabel::Datetime64(*v),
             Scalar::Period(v) => IndexLabel::Utf8(v.calendar_string()),
             Scalar::Interval(iv) => IndexLabel::Utf8(format!("{iv}")),
+            Scalar::Decimal(d) => IndexLabel::Utf8(d.to_string()),
+            nested @ (Scalar::List(_) | Scalar::Struct(_)) => IndexLabel::Utf8(nested.to_string()),
         });
         out_values.push(Scalar::Float64(sum));
     }
@@ -532,6 +535,8 @@ enum GroupKeyRef<'a> {
     Datetime64(i64),
     Period(i64),
     Interval(u64, u64, fp_types::IntervalClosed),
+    Decimal(i128, u8),
+    Nested(String),
 }
 
 impl<'a> GroupKeyRef<'a> {
@@ -573,6 +578,11 @@ impl<'a> GroupKeyRef<'a> {
             Scalar::Interval(iv) => {
                 Self::Interval(iv.left.to_bits(), iv.right.to_bits(), iv.closed)
             }
+            Scalar::Decimal(d) => {
+                let d = d.normalized();
+                Self::Decimal(d.value, d.scale)
+            }
+            nested @ (Scalar::List(_) | Scalar::Struct(_)) => Self::Nested(format!("{nested:?}")),
         }
     }
 }
@@ -682,6 +692,8 @@ fn groupby_sum_timedelta64(
             Scalar::Datetime64(v) => IndexLabel::Datetime64(*v),
             Scalar::Period(v) => IndexLabel::Utf8(v.calendar_string()),
             Scalar::Interval(iv) => IndexLabel::Utf8(format!("{iv}")),
+            Scalar::Decimal(d) => IndexLabel::Utf8(d.to_string()),
+            nested @ (Scalar::List(_) | Scalar::Struct(_)) => IndexLabel::Utf8(nested.to_string()),
         });
         out_values.push(Scalar::Timedelta64(sum));
     }
@@ -766,6 +778,8 @@ fn groupby_sum_utf8(
             Scalar::Datetime64(v) => IndexLabel::Datetime64(*v),
             Scalar::Period(v) => IndexLabel::Utf8(v.calendar_string()),
             Scalar::Interval(iv) => IndexLabel::Utf8(format!("{iv}")),
+            Scalar::Decimal(d) => IndexLabel::Utf8(d.to_string()),
+            nested @ (Scalar::List(_) | Scalar::Struct(_)) => IndexLabel::Utf8(nested.to_string()),
         });
         out_values.push(Scalar::Utf8(joined));
     }
@@ -877,6 +891,8 @@ fn groupby_sum_int64(
             Scalar::Datetime64(v) => IndexLabel::Datetime64(*v),
             Scalar::Period(v) => IndexLabel::Utf8(v.calendar_string()),
             Scalar::Interval(iv) => IndexLabel::Utf8(format!("{iv}")),
+            Scalar::Decimal(d) => IndexLabel::Utf8(d.to_string()),
+            nested @ (Scalar::List(_) | Scalar::Struct(_)) => IndexLabel::Utf8(nested.to_string()),
         });
         out_values.push(match i64::try_from(total) {
             Ok(v) => Scalar::Int64(v),
@@ -1822,6 +1838,8 @@ fn try_groupby_count_size_counter(
             Scalar::Datetime64(v) => IndexLabel::Datetime64(*v),
             Scalar::Period(v) => IndexLabel::Utf8(v.calendar_string()),
             Scalar::Interval(iv) => IndexLabel::Utf8(format!("{iv}")),
+            Scalar::Decimal(d) => IndexLabel::Utf8(d.to_string()),
+            nested @ (Scalar::List(_) | Scalar::Struct(_)) => IndexLabel::Utf8(nested.to_string()),
         });
         out_values.push(Scalar::Int64(if matches!(func, AggFunc::Count) {
             *non_missing
@@ -1897,6 +1915,8 @@ fn try_groupby_mean_numeric_counter(
             Scalar::Datetime64(v) => IndexLabel::Datetime64(*v),
             Scalar::Period(v) => IndexLabel::Utf8(v.calendar_string()),
             Scalar::Interval(iv) => IndexLabel::Utf8(format!("{iv}")),
+            Scalar::Decimal(d) => IndexLabel::Utf8(d.to_string()),
+            nested @ (Scalar::List(_) | Scalar::Struct(_)) => IndexLabel::Utf8(nested.to_string()),
         });
         out_values.push(if *count == 0 {
             Scalar::Null(NullKind::NaN)
@@ -2012,6 +2032,8 @@ fn try_groupby_var_std_numeric_counter(
             Scalar::Datetime64(v) => IndexLabel::Datetime64(*v),
             Scalar::Period(v) => IndexLabel::Utf8(v.calendar_string()),
             Scalar::Interval(iv) => IndexLabel::Utf8(format!("{iv}")),
+            Scalar::Decimal(d) => IndexLabel::Utf8(d.to_string()),
+            nested @ (Scalar::List(_) | Scalar::Struct(_)) => IndexLabel::Utf8(nested.to_string()),
         });
         out_values.push(if group.count <= 1 {
             Scalar::Null(NullKind::NaN)
@@ -2040,6 +2062,8 @@ fn scalar_group_label(label: &Scalar) -> IndexLabel {
         Scalar::Datetime64(v) => IndexLabel::Datetime64(*v),
         Scalar::Period(v) => IndexLabel::Utf8(v.calendar_string()),
         Scalar::Interval(iv) => IndexLabel::Utf8(format!("{iv}")),
+        Scalar::Decimal(d) => IndexLabel::Utf8(d.to_string()),
+        nested @ (Scalar::List(_) | Scalar::Struct(_)) => IndexLabel::Utf8(nested.to_string()),
     }
 }
 
@@ -2140,6 +2164,8 @@ enum NuniqueValueKey<'a> {
     Datetime64(i64),
     Period(i64, PeriodFreq),
     Interval(u64, u64, IntervalClosed),
+    Decimal(i128, u8),
+    Nested(String),
 }
 
 fn nunique_value_key(value: &Scalar) -> Option<NuniqueValueKey<'_>> {
@@ -2162,6 +2188,13 @@ fn nunique_value_key(value: &Scalar) -> Option<NuniqueValueKey<'_>> {
             if v.right == 0.0 { 0.0 } else { v.right }.to_bits(),
             v.closed,
         ),
+        Scalar::Decimal(v) => {
+            let v = v.normalized();
+            NuniqueValueKey::Decimal(v.value, v.scale)
+        }
+        nested @ (Scalar::List(_) | Scalar::Struct(_)) => {
+            NuniqueValueKey::Nested(format!("{nested:?}"))
+        }
         Scalar::Null(_) => return None,
     })
 }
@@ -2362,6 +2395,8 @@ fn try_groupby_min_max_scalar_slot(
             Scalar::Datetime64(v) => IndexLabel::Datetime64(*v),
             Scalar::Period(v) => IndexLabel::Utf8(v.calendar_string()),
             Scalar::Interval(iv) => IndexLabel::Utf8(format!("{iv}")),
+            Scalar::Decimal(d) => IndexLabel::Utf8(d.to_string()),
+            nested @ (Scalar::List(_) | Scalar::Struct(_)) => IndexLabel::Utf8(nested.to_string()),
         });
         out_values.push(if *invalid {
             Scalar::Null(NullKind::NaN)
@@ -2440,6 +2475,8 @@ fn try_groupby_first_last_scalar_slot(
             Scalar::Datetime64(v) => IndexLabel::Datetime64(*v),
             Scalar::Period(v) => IndexLabel::Utf8(v.calendar_string()),
             Scalar::Interval(iv) => IndexLabel::Utf8(format!("{iv}")),
+            Scalar::Decimal(d) => IndexLabel::Utf8(d.to_string()),
+            nested @ (Scalar::List(_) | Scalar::Struct(_)) => IndexLabel::Utf8(nested.to_string()),
         });
         out_values.push(slot.clone().unwrap_or(Scalar::Null(NullKind::NaN)));
     }
@@ -2545,6 +2582,8 @@ fn try_groupby_sum_prod_integer_counter(
             Scalar::Datetime64(v) => IndexLabel::Datetime64(*v),
             Scalar::Period(v) => IndexLabel::Utf8(v.calendar_string()),
             Scalar::Interval(iv) => IndexLabel::Utf8(format!("{iv}")),
+            Scalar::Decimal(d) => IndexLabel::Utf8(d.to_string()),
+            nested @ (Scalar::List(_) | Scalar::Struct(_)) => IndexLabel::Utf8(nested.to_string()),
         });
         out_values.push(if take_sum {
             match i64::try_from(group.sum) {
@@ -2639,6 +2678,8 @@ fn try_groupby_sum_prod_float_counter(
             Scalar::Datetime64(v) => IndexLabel::Datetime64(*v),
             Scalar::Period(v) => IndexLabel::Utf8(v.calendar_string()),
             Scalar::Interval(iv) => IndexLabel::Utf8(format!("{iv}")),
+            Scalar::Decimal(d) => IndexLabel::Utf8(d.to_string()),
+            nested @ (Scalar::List(_) | Scalar::Struct(_)) => IndexLabel::Utf8(nested.to_string()),
         });
         out_values.push(Scalar::Float64(if take_sum {
             group.sum
@@ -2657,6 +2698,91 @@ pub fn groupby_agg(
     options: GroupByOptions,
     policy: &RuntimePolicy,
     ledger: &mut EvidenceLedger,
+) -> Result<Series, GroupByError> {
+    let masked = masked_result_dtype(values.column().dtype(), func);
+    groupby_agg_with_result_dtype(keys, values, func, masked, options, policy, ledger)
+}
+
+/// pandas keeps a masked value column masked through a reduction: an `Int64`
+/// (extension) sum, min or max is `Int64`, and `boolean` first/last stay
+/// `boolean`. Count/size/nunique are numpy `int64` in pandas as well, and the
+/// float reductions are left to the accumulators.
+fn masked_result_dtype(source: DType, func: AggFunc) -> Option<DType> {
+    match (source, func) {
+        (
+            _,
+            AggFunc::Count
+            | AggFunc::Size
+            | AggFunc::Nunique
+            | AggFunc::Mean
+            | AggFunc::Median
+            | AggFunc::Std
+            | AggFunc::Var,
+        ) => None,
+        (DType::Int64Nullable, _) | (DType::BoolNullable, AggFunc::Sum | AggFunc::Prod) => {
+            Some(DType::Int64Nullable)
+        }
+        (DType::BoolNullable, _) => Some(DType::BoolNullable),
+        _ => None,
+    }
+}
+
+/// The result column of one aggregation. The accumulators only see scalars,
+/// so inferring the dtype from them would turn a masked `[5, <NA>]` (a group
+/// with no valid values) into Float64 and round IDs above 2**53; a masked
+/// source lane builds the masked dtype directly instead.
+fn agg_result_column(values: Vec<Scalar>, masked: Option<DType>) -> Result<Column, GroupByError> {
+    let fits = |value: &Scalar| {
+        value.is_missing()
+            || matches!(
+                (masked, value),
+                (Some(DType::Int64Nullable), Scalar::Int64(_))
+                    | (Some(DType::BoolNullable), Scalar::Bool(_))
+            )
+    };
+    match masked {
+        Some(dtype) if values.iter().all(fits) => {
+            let values = values
+                .into_iter()
+                .map(|value| {
+                    if value.is_missing() {
+                        Scalar::Null(NullKind::Null)
+                    } else {
+                        value
+                    }
+                })
+                .collect();
+            Ok(Column::new(dtype, values)?)
+        }
+        _ => Ok(Column::from_values(values)?),
+    }
+}
+
+/// Re-tag a finished reduction of a masked lane (see [`masked_result_dtype`]).
+fn keep_masked_result(
+    result: Series,
+    source: DType,
+    func: AggFunc,
+) -> Result<Series, GroupByError> {
+    let Some(masked) = masked_result_dtype(source, func) else {
+        return Ok(result);
+    };
+    let column = agg_result_column(result.values().to_vec(), Some(masked))?;
+    Ok(Series::new(
+        result.name().to_owned(),
+        result.index().clone(),
+        column,
+    )?)
+}
+
+fn groupby_agg_with_result_dtype(
+    keys: &Series,
+    values: &Series,
+    func: AggFunc,
+    masked: Option<DType>,
+    options: GroupByOptions,
+    policy: &RuntimePolicy,
+    ledger: &mut EvidenceLedger,
 ) -> Result<Series, GroupByError> {
     // Alignment: if indexes differ, align to union.
     let aligned_storage = if keys.index() == values.index() && !keys.index().has_duplicates() {
@@ -2690,7 +2816,7 @@ pub fn groupby_agg(
         && let Some((out_index, out_values)) =
             try_groupby_mean_dense_int64_slices(raw_keys, raw_values, options.sort)
     {
-        let out_column = Column::from_values(out_values)?;
+        let out_column = agg_result_column(out_values, masked)?;
         return Ok(Series::new("mean", Index::new(out_index), out_column)?);
     }
 
@@ -2729,7 +2855,7 @@ pub fn groupby_agg(
     if let Some((out_index, out_values)) =
         try_groupby_agg_dense_int64(key_vals, val_vals, func, options.dropna, options.sort)
     {
-        let out_column = Column::from_values(out_values)?;
+        let out_column = agg_result_column(out_values, masked)?;
         return Ok(Series::new(agg_name, Index::new(out_index), out_column)?);
     }
 
@@ -2739,7 +2865,7 @@ pub fn groupby_agg(
     if let Some((out_index, out_values)) =
         try_groupby_count_size_counter(key_vals, val_vals, func, options.dropna, options.sort)
     {
-        let out_column = Column::from_values(out_values)?;
+        let out_column = agg_result_column(out_values, masked)?;
         return Ok(Series::new(agg_name, Index::new(out_index), out_column)?);
     }
 
@@ -2750,7 +2876,7 @@ pub fn groupby_agg(
         && let Some((out_index, out_values)) =
             try_groupby_mean_numeric_counter(key_vals, val_vals, options.dropna, options.sort)
     {
-        let out_column = Column::from_values(out_values)?;
+        let out_column = agg_result_column(out_values, masked)?;
         return Ok(Series::new(agg_name, Index::new(out_index), out_column)?);
     }
 
@@ -2765,7 +2891,7 @@ pub fn groupby_agg(
             options.sort,
         )
     {
-        let out_column = Column::from_values(out_values)?;
+        let out_column = agg_result_column(out_values, masked)?;
         return Ok(Series::new(agg_name, Index::new(out_index), out_column)?);
     }
 
@@ -2776,7 +2902,7 @@ pub fn groupby_agg(
         && let Some((out_index, out_values)) =
             try_groupby_median_dense_int64(key_vals, val_vals, options.dropna, options.sort)
     {
-        let out_column = Column::from_values(out_values)?;
+        let out_column = agg_result_column(out_values, masked)?;
         return Ok(Series::new(agg_name, Index::new(out_index), out_column)?);
     }
 
@@ -2787,7 +2913,7 @@ pub fn groupby_agg(
         && let Some((out_index, out_values)) =
             try_groupby_median_numeric_vectors(key_vals, val_vals, options.dropna, options.sort)
     {
-        let out_column = Column::from_values(out_values)?;
+        let out_column = agg_result_column(out_values, masked)?;
         return Ok(Series::new(agg_name, Index::new(out_index), out_column)?);
     }
 
@@ -2798,7 +2924,7 @@ pub fn groupby_agg(
         && let Some((out_index, out_values)) =
             try_groupby_min_max_scalar_slot(key_vals, val_vals, func, options.dropna, options.sort)
     {
-        let out_column = Column::from_values(out_values)?;
+        let out_column = agg_result_column(out_values, masked)?;
         return Ok(Series::new(agg_name, Index::new(out_index), out_column)?);
     }
 
@@ -2814,7 +2940,7 @@ pub fn groupby_agg(
             options.sort,
         )
     {
-        let out_column = Column::from_values(out_values)?;
+        let out_column = agg_result_column(out_values, masked)?;
         return Ok(Series::new(agg_name, Index::new(out_index), out_column)?);
     }
 
@@ -2831,7 +2957,7 @@ pub fn groupby_agg(
             options.sort,
         )
     {
-        let out_column = Column::from_values(out_values)?;
+        let out_column = agg_result_column(out_values, masked)?;
         return Ok(Series::new(agg_name, Index::new(out_index), out_column)?);
     }
 
@@ -2847,7 +2973,7 @@ pub fn groupby_agg(
             options.sort,
         )
     {
-        let out_column = Column::from_values(out_values)?;
+        let out_column = agg_result_column(out_values, masked)?;
         return Ok(Series::new(agg_name, Index::new(out_index), out_column)?);
     }
 
@@ -2856,7 +2982,7 @@ pub fn groupby_agg(
         && let Some((out_index, out_values)) =
             try_groupby_nunique_dense_int64(key_vals, val_vals, options.dropna, options.sort)
     {
-        let out_column = Column::from_values(out_values)?;
+        let out_column = agg_result_column(out_values, masked)?;
         return Ok(Series::new(agg_name, Index::new(out_index), out_column)?);
     }
 
@@ -2866,7 +2992,7 @@ pub fn groupby_agg(
     if matches!(func, AggFunc::Nunique) {
         let (out_index, out_values) =
             try_groupby_nunique_borrowed_sets(key_vals, val_vals, options.dropna, options.sort);
-        let out_column = Column::from_values(out_values)?;
+        let out_column = agg_result_column(out_values, masked)?;
         return Ok(Series::new(agg_name, Index::new(out_index), out_column)?);
     }
 
@@ -2925,6 +3051,8 @@ pub fn groupby_agg(
             Scalar::Datetime64(v) => IndexLabel::Datetime64(*v),
             Scalar::Period(v) => IndexLabel::Utf8(v.calendar_string()),
             Scalar::Interval(iv) => IndexLabel::Utf8(format!("{iv}")),
+            Scalar::Decimal(d) => IndexLabel::Utf8(d.to_string()),
+            nested @ (Scalar::List(_) | Scalar::Struct(_)) => IndexLabel::Utf8(nested.to_string()),
         });
 
         let agg_value = match func {
@@ -3003,7 +3131,7 @@ pub fn groupby_agg(
         out_values.push(agg_value);
     }
 
-    let out_column = Column::from_values(out_values)?;
+    let out_column = agg_result_column(out_values, masked)?;
     Ok(Series::new(agg_name, Index::new(out_index), out_column)?)
 }
 
@@ -3175,6 +3303,13 @@ fn scalar_to_hash_bits(value: &Scalar) -> u64 {
         Scalar::Datetime64(v) => *v as u64,
         Scalar::Period(v) => v.ordinal as u64,
         Scalar::Interval(iv) => iv.left.to_bits() ^ iv.right.to_bits(),
+        Scalar::Decimal(d) => {
+            let d = d.normalized();
+            (d.value as u64) ^ ((d.value >> 64) as u64) ^ u64::from(d.scale)
+        }
+        nested @ (Scalar::List(_) | Scalar::Struct(_)) => {
+            scalar_to_hash_bits(&Scalar::Utf8(format!("{nested:?}")))
+        }
     }
 }
 
@@ -5018,6 +5153,111 @@ mod tests {
         assert_eq!(out.values(), &[Scalar::Int64(6), Scalar::Int64(3)]);
     }
 
+    #[test]
+    fn groupby_keeps_masked_int64_values_masked() {
+        let labels: Vec<IndexLabel> = (0..4_i64).map(IndexLabel::Int64).collect();
+        let keys = Series::from_values(
+            "key",
+            labels.clone(),
+            vec![
+                Scalar::Int64(1),
+                Scalar::Int64(1),
+                Scalar::Int64(2),
+                Scalar::Int64(2),
+            ],
+        )
+        .expect("keys");
+        let values = Series::new(
+            "value",
+            Index::new(labels),
+            Column::new(
+                DType::Int64Nullable,
+                vec![
+                    Scalar::Int64(9_007_199_254_740_993),
+                    Scalar::Null(NullKind::Null),
+                    Scalar::Int64(4),
+                    Scalar::Int64(5),
+                ],
+            )
+            .expect("masked column"),
+        )
+        .expect("values");
+
+        let mut ledger = EvidenceLedger::new();
+        let sum = groupby_sum(
+            &keys,
+            &values,
+            GroupByOptions::default(),
+            &RuntimePolicy::strict(),
+            &mut ledger,
+        )
+        .expect("sum");
+        assert_eq!(sum.column().dtype(), DType::Int64Nullable);
+        assert_eq!(
+            sum.values(),
+            &[Scalar::Int64(9_007_199_254_740_993), Scalar::Int64(9)]
+        );
+
+        let max = super::groupby_max(
+            &keys,
+            &values,
+            GroupByOptions::default(),
+            &RuntimePolicy::strict(),
+            &mut ledger,
+        )
+        .expect("max");
+        assert_eq!(max.column().dtype(), DType::Int64Nullable);
+
+        // Counts are numpy int64 in pandas too.
+        let count = super::groupby_count(
+            &keys,
+            &values,
+            GroupByOptions::default(),
+            &RuntimePolicy::strict(),
+            &mut ledger,
+        )
+        .expect("count");
+        assert_eq!(count.column().dtype(), DType::Int64);
+
+        // A group with no valid values is <NA>, not a reason to widen the
+        // whole result to Float64.
+        let sparse = Series::new(
+            "value",
+            values.index().clone(),
+            Column::new(
+                DType::Int64Nullable,
+                vec![
+                    Scalar::Int64(9_007_199_254_740_993),
+                    Scalar::Null(NullKind::Null),
+                    Scalar::Null(NullKind::Null),
+                    Scalar::Null(NullKind::Null),
+                ],
+            )
+            .expect("sparse column"),
+        )
+        .expect("sparse values");
+        for func in [AggFunc::Min, AggFunc::Max, AggFunc::First, AggFunc::Last] {
+            let out = super::groupby_agg(
+                &keys,
+                &sparse,
+                func,
+                GroupByOptions::default(),
+                &RuntimePolicy::strict(),
+                &mut ledger,
+            )
+            .expect("agg");
+            assert_eq!(out.column().dtype(), DType::Int64Nullable, "{func:?}");
+            assert_eq!(
+                out.values(),
+                &[
+                    Scalar::Int64(9_007_199_254_740_993),
+                    Scalar::Null(NullKind::Null)
+                ],
+                "{func:?}"
+            );
+        }
+    }
+
     #[test]
     fn groupby_sum_int_dense_path_sorts_keys_by_default() {
         let keys = Series::from_values(
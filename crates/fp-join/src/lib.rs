@@ -10834,14 +10834,19 @@ impl<'a> ByKey<'a> {
             Scalar::Timedelta64(v) => ByKey::Timedelta(*v),
             Scalar::Datetime64(v) => ByKey::Datetime(*v),
             Scalar::Period(v) => ByKey::Period(v.ordinal),
-            Scalar::Float64(_) | Scalar::Interval(_) => return None,
+            Scalar::Float64(_)
+            | Scalar::Interval(_)
+            | Scalar::Decimal(_)
+            | Scalar::List(_)
+            | Scalar::Struct(_) => return None,
         })
     }
 }
 
 /// Factorize a single `by` column over both frames into a shared u32 id space.
 /// Returns `None` (caller falls back to the string path) if any value is a
-/// `Float64`/`Interval` whose Debug string would not agree with typed equality.
+/// `Float64`/`Interval`/`Decimal` whose Debug string would not agree with
+/// typed equality.
 fn try_factorize_typed<'a>(
     left: &'a [Scalar],
     right: &'a [Scalar],
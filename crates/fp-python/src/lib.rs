@@ -36,13 +36,21 @@ fn parse_dtype(name: &str) -> PyResult<fp_types::DType> {
     match name {
         "int" | "int64" | "i64" | "Int64" => Ok(DType::Int64),
         "float" | "float64" | "f64" | "Float64" => Ok(DType::Float64),
+        "int8" => Ok(DType::Int8),
+        "int16" => Ok(DType::Int16),
+        "int32" => Ok(DType::Int32),
+        "uint8" => Ok(DType::UInt8),
+        "uint16" => Ok(DType::UInt16),
+        "uint32" => Ok(DType::UInt32),
+        "uint64" => Ok(DType::UInt64),
+        "float32" | "f32" => Ok(DType::Float32),
         "str" | "string" | "object" | "O" | "utf8" => Ok(DType::Utf8),
         "bool" | "boolean" => Ok(DType::Bool),
         "datetime64" | "datetime64[ns]" | "datetime" => Ok(DType::Datetime64),
         "timedelta64" | "timedelta64[ns]" | "timedelta" => Ok(DType::Timedelta64),
-        other => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
-            "unsupported dtype {other:?}"
-        ))),
+        other => fp_types::parse_decimal_dtype(other).ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("unsupported dtype {other:?}"))
+        }),
     }
 }
 
@@ -93,6 +101,26 @@ fn scalar_to_py(py: Python<'_>, scalar: &Scalar) -> PyResult<Py<PyAny>> {
         Scalar::Timedelta64(ns) => ns.into_py_any(py),
         Scalar::Period(p) => p.ordinal.into_py_any(py),
         Scalar::Interval(_) => Ok(py.None()),
+        // `decimal.Decimal`, so a ledger value crosses into Python exactly.
+        Scalar::Decimal(d) => Ok(py
+            .import("decimal")?
+            .getattr("Decimal")?
+            .call1((d.to_string(),))?
+            .unbind()),
+        Scalar::List(items) => {
+            let items = items
+                .iter()
+                .map(|item| scalar_to_py(py, item))
+                .collect::<PyResult<Vec<_>>>()?;
+            Ok(PyList::new(py, items)?.into_any().unbind())
+        }
+        Scalar::Struct(fields) => {
+            let dict = PyDict::new(py);
+            for (name, value) in fields {
+                dict.set_item(name, scalar_to_py(py, value)?)?;
+            }
+            Ok(dict.into_any().unbind())
+        }
     }
 }
 
@@ -105,6 +105,30 @@ pub enum DType {
     /// `Int64Nullable`/`Int64` already are. (br-frankenpandas-qkqfb)
     #[serde(rename = "Float64")]
     Float64Nullable,
+    /// Sized numpy integers. Values are carried as `Scalar::Int64`; the dtype
+    /// records the storage width, and casts into it are range-checked.
+    Int8,
+    Int16,
+    Int32,
+    /// Unsigned numpy integers. `UInt64` values above `i64::MAX` are refused
+    /// on cast, since `Scalar::Int64` is the carrier for every integer lane.
+    #[serde(rename = "uint8")]
+    UInt8,
+    #[serde(rename = "uint16")]
+    UInt16,
+    #[serde(rename = "uint32")]
+    UInt32,
+    #[serde(rename = "uint64")]
+    UInt64,
+    /// numpy `float32`. Values are carried as `Scalar::Float64` already rounded
+    /// to single precision.
+    Float32,
+    /// Exact fixed-point decimal backed by an `i128`. Matches pyarrow
+    /// `decimal128(precision, scale)`; values are carried as `Scalar::Decimal`.
+    Decimal {
+        precision: u8,
+        scale: u8,
+    },
     #[serde(alias = "string", alias = "str")]
     Utf8,
     Categorical,
@@ -116,28 +140,77 @@ pub enum DType {
     /// Numeric interval value. Matches pandas `interval[float64]`.
     Interval,
     Sparse,
+    /// Variable-length list. Matches pandas `ArrowDtype(pa.list_(...))`;
+    /// values are carried as `Scalar::List` and keep their own element dtypes.
+    List,
+    /// Named fields. Matches pandas `ArrowDtype(pa.struct(...))`; values are
+    /// carried as `Scalar::Struct`.
+    Struct,
 }
 
 impl DType {
     /// Returns true if the dtype is numeric (integer or floating point).
     #[must_use]
     pub const fn is_numeric(&self) -> bool {
-        matches!(
-            self,
-            Self::Int64 | Self::Int64Nullable | Self::Float64 | Self::Float64Nullable
-        )
+        self.is_integer() || self.is_floating()
     }
 
     /// Returns true if the dtype is an integer type.
     #[must_use]
     pub const fn is_integer(&self) -> bool {
-        matches!(self, Self::Int64 | Self::Int64Nullable)
+        self.is_signed_integer() || self.is_unsigned_integer()
     }
 
     /// Returns true if the dtype is a floating point type.
     #[must_use]
     pub const fn is_floating(&self) -> bool {
-        matches!(self, Self::Float64 | Self::Float64Nullable)
+        matches!(self, Self::Float32 | Self::Float64 | Self::Float64Nullable)
+    }
+
+    /// Returns true for the sized numpy dtypes other than the `int64` /
+    /// `float64` defaults (`int8`..`int32`, `uint8`..`uint64`, `float32`).
+    #[must_use]
+    pub const fn is_narrow_numeric(&self) -> bool {
+        matches!(
+            self,
+            Self::Int8
+                | Self::Int16
+                | Self::Int32
+                | Self::UInt8
+                | Self::UInt16
+                | Self::UInt32
+                | Self::UInt64
+                | Self::Float32
+        )
+    }
+
+    /// Inclusive value range of an integer dtype, as carried in `Scalar::Int64`.
+    ///
+    /// `UInt64` reports `i64::MAX` as its upper bound: the storage width is
+    /// 64 unsigned bits but the scalar carrier is signed, so larger values
+    /// cannot be represented and are refused on cast.
+    #[must_use]
+    pub const fn integer_range(&self) -> Option<(i64, i64)> {
+        match self {
+            Self::Int8 => Some((i8::MIN as i64, i8::MAX as i64)),
+            Self::Int16 => Some((i16::MIN as i64, i16::MAX as i64)),
+            Self::Int32 => Some((i32::MIN as i64, i32::MAX as i64)),
+            Self::Int64 | Self::Int64Nullable => Some((i64::MIN, i64::MAX)),
+            Self::UInt8 => Some((0, u8::MAX as i64)),
+            Self::UInt16 => Some((0, u16::MAX as i64)),
+            Self::UInt32 => Some((0, u32::MAX as i64)),
+            Self::UInt64 => Some((0, i64::MAX)),
+            _ => None,
+        }
+    }
+
+    /// Returns true for `decimal128(precision, scale)`.
+    ///
+    /// Not counted by [`DType::is_numeric`]: the numeric kernels work in
+    /// `i64`/`f64`, and routing a decimal through them would lose exactness.
+    #[must_use]
+    pub const fn is_decimal(&self) -> bool {
+        matches!(self, Self::Decimal { .. })
     }
 
     /// Returns true if the dtype is boolean.
@@ -188,6 +261,12 @@ impl DType {
         matches!(self, Self::Interval)
     }
 
+    /// Returns true for the nested `List` and `Struct` dtypes.
+    #[must_use]
+    pub const fn is_nested(&self) -> bool {
+        matches!(self, Self::List | Self::Struct)
+    }
+
     /// Return the dtype name as a string.
     ///
     /// Matches numpy dtype.name property.
@@ -202,6 +281,15 @@ impl DType {
             // MEASURED: str(pd.Float64Dtype()) == "Float64", the same
             // capital-letter convention as Int64/boolean.
             Self::Float64Nullable => "Float64",
+            Self::Int8 => "int8",
+            Self::Int16 => "int16",
+            Self::Int32 => "int32",
+            Self::UInt8 => "uint8",
+            Self::UInt16 => "uint16",
+            Self::UInt32 => "uint32",
+            Self::UInt64 => "uint64",
+            Self::Float32 => "float32",
+            Self::Decimal { .. } => "decimal128",
             Self::Utf8 => "object",
             Self::Datetime64 => "datetime64[ns]",
             Self::Timedelta64 => "timedelta64[ns]",
@@ -209,6 +297,8 @@ impl DType {
             Self::Period => "period",
             Self::Interval => "interval",
             Self::Sparse => "Sparse",
+            Self::List => "list",
+            Self::Struct => "struct",
             Self::Null => "object",
         }
     }
@@ -220,11 +310,14 @@ impl DType {
     pub const fn kind(&self) -> char {
         match self {
             Self::Bool | Self::BoolNullable => 'b',
-            Self::Int64 | Self::Int64Nullable => 'i',
+            Self::Int8 | Self::Int16 | Self::Int32 | Self::Int64 | Self::Int64Nullable => 'i',
+            Self::UInt8 | Self::UInt16 | Self::UInt32 | Self::UInt64 => 'u',
             // MEASURED: pd.Float64Dtype().kind == 'f', matching numpy float64 —
             // the extension dtype reports its underlying kind, exactly as
             // Int64Nullable reports 'i'.
-            Self::Float64 | Self::Float64Nullable => 'f',
+            Self::Float32 | Self::Float64 | Self::Float64Nullable => 'f',
+            // An ArrowDtype decimal reports its numpy fallback, `object`.
+            Self::Decimal { .. } => 'O',
             Self::Utf8 => 'O',
             Self::Datetime64 => 'M',
             Self::Timedelta64 => 'm',
@@ -232,6 +325,7 @@ impl DType {
             Self::Period => 'O',
             Self::Interval => 'O',
             Self::Sparse => 'O',
+            Self::List | Self::Struct => 'O',
             Self::Null => 'O',
         }
     }
@@ -242,15 +336,25 @@ impl DType {
     #[must_use]
     pub const fn itemsize(&self) -> usize {
         match self {
-            Self::Bool | Self::BoolNullable => 1,
-            Self::Int64
+            Self::Bool | Self::BoolNullable | Self::Int8 | Self::UInt8 => 1,
+            Self::Int16 | Self::UInt16 => 2,
+            Self::Int32 | Self::UInt32 | Self::Float32 => 4,
+            Self::UInt64
+            | Self::Int64
             | Self::Int64Nullable
             | Self::Float64
             | Self::Float64Nullable
             | Self::Datetime64
             | Self::Timedelta64
             | Self::Period => 8,
-            Self::Utf8 | Self::Categorical | Self::Interval | Self::Sparse | Self::Null => 8,
+            Self::Decimal { .. } => 16,
+            Self::Utf8
+            | Self::Categorical
+            | Self::Interval
+            | Self::Sparse
+            | Self::Null
+            | Self::List
+            | Self::Struct => 8,
         }
     }
 
@@ -268,6 +372,9 @@ impl DType {
                 | Self::Int64Nullable
                 | Self::Float64Nullable
                 | Self::BoolNullable
+                | Self::Decimal { .. }
+                | Self::List
+                | Self::Struct
         )
     }
 
@@ -279,7 +386,12 @@ impl DType {
     pub const fn is_nullable(&self) -> bool {
         matches!(
             self,
-            Self::Int64Nullable | Self::Float64Nullable | Self::BoolNullable
+            Self::Int64Nullable
+                | Self::Float64Nullable
+                | Self::BoolNullable
+                | Self::Decimal { .. }
+                | Self::List
+                | Self::Struct
         )
     }
 
@@ -316,7 +428,21 @@ impl DType {
     /// Matches `pd.api.types.is_signed_integer_dtype()`.
     #[must_use]
     pub const fn is_signed_integer(&self) -> bool {
-        matches!(self, Self::Int64 | Self::Int64Nullable)
+        matches!(
+            self,
+            Self::Int8 | Self::Int16 | Self::Int32 | Self::Int64 | Self::Int64Nullable
+        )
+    }
+
+    /// Returns true if this is an unsigned integer type.
+    ///
+    /// Matches `pd.api.types.is_unsigned_integer_dtype()`.
+    #[must_use]
+    pub const fn is_unsigned_integer(&self) -> bool {
+        matches!(
+            self,
+            Self::UInt8 | Self::UInt16 | Self::UInt32 | Self::UInt64
+        )
     }
 
     /// Returns true for the Utf8 dtype.
@@ -366,12 +492,27 @@ impl DType {
     pub const fn char(&self) -> char {
         match self {
             Self::Bool | Self::BoolNullable => '?',
+            Self::Int8 => 'b',
+            Self::Int16 => 'h',
+            Self::Int32 => 'i',
             Self::Int64 | Self::Int64Nullable => 'l',
+            Self::UInt8 => 'B',
+            Self::UInt16 => 'H',
+            Self::UInt32 => 'I',
+            Self::UInt64 => 'L',
+            Self::Float32 => 'f',
             Self::Float64 | Self::Float64Nullable => 'd',
             Self::Utf8 => 'O',
             Self::Datetime64 => 'M',
             Self::Timedelta64 => 'm',
-            Self::Categorical | Self::Period | Self::Interval | Self::Sparse | Self::Null => 'O',
+            Self::Categorical
+            | Self::Period
+            | Self::Interval
+            | Self::Sparse
+            | Self::Null
+            | Self::Decimal { .. }
+            | Self::List
+            | Self::Struct => 'O',
         }
     }
 
@@ -382,12 +523,27 @@ impl DType {
     pub const fn num(&self) -> i32 {
         match self {
             Self::Bool | Self::BoolNullable => 0,
+            Self::Int8 => 1,
+            Self::UInt8 => 2,
+            Self::Int16 => 3,
+            Self::UInt16 => 4,
+            Self::Int32 => 5,
+            Self::UInt32 => 6,
             Self::Int64 | Self::Int64Nullable => 7,
+            Self::UInt64 => 8,
+            Self::Float32 => 11,
             Self::Float64 | Self::Float64Nullable => 12,
             Self::Utf8 => 17,
             Self::Datetime64 => 21,
             Self::Timedelta64 => 22,
-            Self::Categorical | Self::Period | Self::Interval | Self::Sparse | Self::Null => 17,
+            Self::Categorical
+            | Self::Period
+            | Self::Interval
+            | Self::Sparse
+            | Self::Null
+            | Self::Decimal { .. }
+            | Self::List
+            | Self::Struct => 17,
         }
     }
 
@@ -406,12 +562,27 @@ impl DType {
     pub const fn str_repr(&self) -> &'static str {
         match self {
             Self::Bool | Self::BoolNullable => "|b1",
+            Self::Int8 => "|i1",
+            Self::Int16 => "<i2",
+            Self::Int32 => "<i4",
             Self::Int64 | Self::Int64Nullable => "<i8",
+            Self::UInt8 => "|u1",
+            Self::UInt16 => "<u2",
+            Self::UInt32 => "<u4",
+            Self::UInt64 => "<u8",
+            Self::Float32 => "<f4",
             Self::Float64 | Self::Float64Nullable => "<f8",
             Self::Utf8 => "|O8",
             Self::Datetime64 => "<M8[ns]",
             Self::Timedelta64 => "<m8[ns]",
-            Self::Categorical | Self::Period | Self::Interval | Self::Sparse | Self::Null => "|O8",
+            Self::Categorical
+            | Self::Period
+            | Self::Interval
+            | Self::Sparse
+            | Self::Null
+            | Self::Decimal { .. }
+            | Self::List
+            | Self::Struct => "|O8",
         }
     }
 }
@@ -510,6 +681,15 @@ pub enum Scalar {
     Period(Period),
     /// Numeric interval value. Missing values remain `Scalar::Null`.
     Interval(Interval),
+    /// Exact fixed-point value of a `decimal128` column. Missing values
+    /// remain `Scalar::Null`.
+    Decimal(Decimal),
+    /// One row of a `List` column. A missing list is `Scalar::Null`; an empty
+    /// list is present.
+    List(Vec<Scalar>),
+    /// One row of a `Struct` column: `(field name, value)` pairs in field
+    /// order. A missing struct is `Scalar::Null`.
+    Struct(Vec<(String, Scalar)>),
 }
 
 impl std::fmt::Display for Scalar {
@@ -538,10 +718,41 @@ impl std::fmt::Display for Scalar {
                 }
             }
             Self::Interval(interval) => write!(f, "{interval}"),
+            Self::Decimal(decimal) => write!(f, "{decimal}"),
+            Self::List(items) => {
+                write!(f, "[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    fmt_nested_item(f, item)?;
+                }
+                write!(f, "]")
+            }
+            Self::Struct(fields) => {
+                write!(f, "{{")?;
+                for (i, (name, value)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "'{name}': ")?;
+                    fmt_nested_item(f, value)?;
+                }
+                write!(f, "}}")
+            }
         }
     }
 }
 
+/// Python-repr style for values inside a list or struct: strings are quoted
+/// so `['a', 'b']` reads like the pandas object it came from.
+fn fmt_nested_item(f: &mut std::fmt::Formatter<'_>, item: &Scalar) -> std::fmt::Result {
+    match item {
+        Scalar::Utf8(s) => write!(f, "'{s}'"),
+        other => write!(f, "{other}"),
+    }
+}
+
 // Ergonomic From impls (br-frankenpandas-esjjy / fd90.182). Mirrors
 // IndexLabel's From<i64>/From<&str>/From<String> so users can write
 //   let v: Vec<Scalar> = vec![1i64.into(), 2.0.into(), "three".into()];
@@ -594,6 +805,9 @@ impl Scalar {
             Self::Datetime64(_) => "Datetime64",
             Self::Period(_) => "Period",
             Self::Interval(_) => "Interval",
+            Self::Decimal(_) => "Decimal",
+            Self::List(_) => "List",
+            Self::Struct(_) => "Struct",
         }
     }
 
@@ -609,6 +823,12 @@ impl Scalar {
             Self::Datetime64(_) => DType::Datetime64,
             Self::Period(_) => DType::Period,
             Self::Interval(_) => DType::Interval,
+            Self::Decimal(decimal) => DType::Decimal {
+                precision: decimal.precision(),
+                scale: decimal.scale,
+            },
+            Self::List(_) => DType::List,
+            Self::Struct(_) => DType::Struct,
         }
     }
 
@@ -683,6 +903,18 @@ impl Scalar {
         matches!(self, Self::Interval(_))
     }
 
+    /// Returns true if this is a Decimal scalar.
+    #[must_use]
+    pub const fn is_decimal(&self) -> bool {
+        matches!(self, Self::Decimal(_))
+    }
+
+    /// Returns true if this is a List or Struct scalar.
+    #[must_use]
+    pub const fn is_nested(&self) -> bool {
+        matches!(self, Self::List(_) | Self::Struct(_))
+    }
+
     #[must_use]
     pub fn missing_for_dtype(dtype: DType) -> Self {
         match dtype {
@@ -695,6 +927,7 @@ impl Scalar {
             // NullKind::NaN would make the two flavours indistinguishable, which
             // is exactly the gap br-frankenpandas-qkqfb was filed for.
             DType::Float64Nullable => Self::Null(NullKind::Null),
+            DType::Float32 => Self::Null(NullKind::NaN),
             DType::Timedelta64 => Self::Timedelta64(Timedelta::NAT),
             DType::Datetime64 => Self::Datetime64(Timestamp::NAT),
             DType::Period => Self::Period(Period::new(i64::MIN, PeriodFreq::Daily)),
@@ -703,10 +936,20 @@ impl Scalar {
             | DType::BoolNullable
             | DType::Int64
             | DType::Int64Nullable
+            | DType::Int8
+            | DType::Int16
+            | DType::Int32
+            | DType::UInt8
+            | DType::UInt16
+            | DType::UInt32
+            | DType::UInt64
             | DType::Utf8
             | DType::Categorical
             | DType::Interval
-            | DType::Sparse => Self::Null(NullKind::Null),
+            | DType::Sparse
+            | DType::Decimal { .. }
+            | DType::List
+            | DType::Struct => Self::Null(NullKind::Null),
         }
     }
 
@@ -843,6 +1086,31 @@ impl Scalar {
                         .unwrap_or(std::cmp::Ordering::Equal)
                 })
                 .then_with(|| a.closed.cmp(&b.closed)),
+            (Self::Decimal(a), Self::Decimal(b)) => a.cmp(b),
+            (Self::Decimal(a), Self::Int64(b)) => a.cmp(&Decimal::new(i128::from(*b), 0)),
+            (Self::Int64(a), Self::Decimal(b)) => Decimal::new(i128::from(*a), 0).cmp(b),
+            (Self::Decimal(a), Self::Float64(b)) => a
+                .to_f64()
+                .partial_cmp(b)
+                .unwrap_or(std::cmp::Ordering::Equal),
+            (Self::Float64(a), Self::Decimal(b)) => a
+                .partial_cmp(&b.to_f64())
+                .unwrap_or(std::cmp::Ordering::Equal),
+            // Lists compare element-wise, then by length, as Python lists do.
+            (Self::List(a), Self::List(b)) => a
+                .iter()
+                .zip(b)
+                .map(|(x, y)| x.semantic_cmp(y))
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or_else(|| a.len().cmp(&b.len())),
+            (Self::Struct(a), Self::Struct(b)) => a
+                .iter()
+                .zip(b)
+                .map(|((x_name, x), (y_name, y))| {
+                    x_name.cmp(y_name).then_with(|| x.semantic_cmp(y))
+                })
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or_else(|| a.len().cmp(&b.len())),
             // Cross-numeric comparison
             (Self::Int64(a), Self::Float64(b)) => (*a as f64)
                 .partial_cmp(b)
@@ -892,6 +1160,11 @@ impl Scalar {
                 value: v.to_string(),
                 dtype: DType::Interval,
             }),
+            Self::Decimal(v) => Ok(v.to_f64()),
+            Self::List(_) | Self::Struct(_) => Err(TypeError::NonNumericValue {
+                value: self.to_string(),
+                dtype: self.dtype(),
+            }),
         }
     }
 
@@ -922,6 +1195,14 @@ impl Scalar {
                 value: v.to_string(),
                 dtype: DType::Interval,
             }),
+            Self::Decimal(v) => v.trunc_to_i64().ok_or_else(|| TypeError::ValueOutOfRange {
+                value: v.to_string(),
+                dtype: DType::Int64,
+            }),
+            Self::List(_) | Self::Struct(_) => Err(TypeError::NonNumericValue {
+                value: self.to_string(),
+                dtype: self.dtype(),
+            }),
         }
     }
 
@@ -946,6 +1227,10 @@ impl Scalar {
             }),
             Self::Period(p) => Ok(p.ordinal != 0),
             Self::Interval(_) => Ok(true),
+            Self::Decimal(v) => Ok(v.value != 0),
+            // Python truthiness: an empty list or struct is falsy.
+            Self::List(items) => Ok(!items.is_empty()),
+            Self::Struct(fields) => Ok(!fields.is_empty()),
         }
     }
 
@@ -971,6 +1256,8 @@ impl Scalar {
             Self::Period(p) if p.ordinal == i64::MIN => "NaT".to_string(),
             Self::Period(p) => p.calendar_string(),
             Self::Interval(v) => v.to_string(),
+            Self::Decimal(v) => v.to_string(),
+            Self::List(_) | Self::Struct(_) => self.to_string(),
         }
     }
 }
@@ -999,6 +1286,10 @@ pub enum TypeError {
     IntervalStepDoesNotDivide { step: f64, span: f64 },
     #[error("cannot parse '{value}' as {target}")]
     ValueNotParseable { value: String, target: String },
+    #[error("value {value} is out of range for {dtype:?}")]
+    ValueOutOfRange { value: String, dtype: DType },
+    #[error("rescaling decimal {value} to scale {scale} would lose digits")]
+    LossyDecimalRescale { value: String, scale: u8 },
 }
 
 pub fn common_dtype(left: DType, right: DType) -> Result<DType, TypeError> {
@@ -1011,6 +1302,10 @@ pub fn common_dtype(left: DType, right: DType) -> Result<DType, TypeError> {
         (a, b) if a == b => a,
         (Null, other) | (other, Null) => other,
         (Categorical, Categorical) => Categorical,
+        (a, b) if a.is_decimal() || b.is_decimal() => return common_decimal_dtype(a, b),
+        (a, b) if a.is_narrow_numeric() || b.is_narrow_numeric() => {
+            return common_numeric_dtype(a, b);
+        }
 
         // Bool promotions (nullable absorbs non-nullable)
         (Bool, Int64) | (Int64, Bool) => Int64,
@@ -1077,6 +1372,148 @@ pub fn common_dtype(left: DType, right: DType) -> Result<DType, TypeError> {
     Ok(out)
 }
 
+/// numpy base type of a numeric dtype: `(kind, bits)` with kind `b`/`i`/`u`/`f`,
+/// plus whether the dtype is a pandas nullable extension.
+const fn numeric_promotion_base(dtype: DType) -> Option<((char, u8), bool)> {
+    Some(match dtype {
+        DType::Bool => (('b', 0), false),
+        DType::BoolNullable => (('b', 0), true),
+        DType::Int8 => (('i', 8), false),
+        DType::Int16 => (('i', 16), false),
+        DType::Int32 => (('i', 32), false),
+        DType::Int64 => (('i', 64), false),
+        DType::Int64Nullable => (('i', 64), true),
+        DType::UInt8 => (('u', 8), false),
+        DType::UInt16 => (('u', 16), false),
+        DType::UInt32 => (('u', 32), false),
+        DType::UInt64 => (('u', 64), false),
+        DType::Float32 => (('f', 32), false),
+        DType::Float64 => (('f', 64), false),
+        DType::Float64Nullable => (('f', 64), true),
+        _ => return None,
+    })
+}
+
+/// Promotion for any pair involving a sized numpy dtype.
+///
+/// The numpy half follows `np.result_type`: same-signedness integers widen,
+/// a signed/unsigned pair takes the smallest signed type holding both (so
+/// `uint64` with any signed integer is `float64`), and `float32` absorbs
+/// `int8`/`int16`/`uint8` while wider integers push it to `float64`.
+///
+/// ONE DELIBERATE DEVIATION: numpy gives `uint16 + float32 -> float32`, and
+/// here it is `float64`. numpy's table is not associative at that corner —
+/// `(int16 ∨ uint16) ∨ float32` is `int32 ∨ float32 = float64` while
+/// `int16 ∨ (uint16 ∨ float32)` is `float32` — and
+/// `common_dtype_lattice_axioms_be314` holds this function to associativity,
+/// because melt/concat fold dtype lists in whatever order the columns arrive.
+///
+/// A nullable operand makes the result nullable. There is no `Int8`/`UInt32`
+/// extension dtype yet, so nullable integer results land on `Int64Nullable`,
+/// and nullable `uint64`/`float32` results on `Float64Nullable`. These are the
+/// same answers the 64-bit arms of [`common_dtype`] already give, which is what
+/// keeps the two halves of the lattice consistent.
+fn common_numeric_dtype(left: DType, right: DType) -> Result<DType, TypeError> {
+    let (Some((lb, ln)), Some((rb, rn))) =
+        (numeric_promotion_base(left), numeric_promotion_base(right))
+    else {
+        return Err(TypeError::IncompatibleDtypes { left, right });
+    };
+    let base = match (lb, rb) {
+        (('b', _), other) | (other, ('b', _)) => other,
+        (('f', a), ('f', b)) => ('f', a.max(b)),
+        (('f', 32), (kind, bits)) | ((kind, bits), ('f', 32)) => {
+            if (kind == 'i' && bits <= 16) || (kind == 'u' && bits <= 8) {
+                ('f', 32)
+            } else {
+                ('f', 64)
+            }
+        }
+        (('f', _), _) | (_, ('f', _)) => ('f', 64),
+        ((a_kind, a), (b_kind, b)) if a_kind == b_kind => (a_kind, a.max(b)),
+        ((a_kind, a), (_, b)) => {
+            let (signed, unsigned) = if a_kind == 'i' { (a, b) } else { (b, a) };
+            let bits = u16::from(signed).max(2 * u16::from(unsigned));
+            if bits <= 64 {
+                ('i', bits as u8)
+            } else {
+                ('f', 64)
+            }
+        }
+    };
+    Ok(match (base, ln || rn) {
+        (('b', _), false) => DType::Bool,
+        (('b', _), true) => DType::BoolNullable,
+        (('i', 8), false) => DType::Int8,
+        (('i', 16), false) => DType::Int16,
+        (('i', 32), false) => DType::Int32,
+        (('i', _), false) => DType::Int64,
+        (('u', 8), false) => DType::UInt8,
+        (('u', 16), false) => DType::UInt16,
+        (('u', 32), false) => DType::UInt32,
+        (('u', _), false) => DType::UInt64,
+        (('f', 32), false) => DType::Float32,
+        (_, false) => DType::Float64,
+        (('i', _), true) => DType::Int64Nullable,
+        (('u', bits), true) if bits < 64 => DType::Int64Nullable,
+        (_, true) => DType::Float64Nullable,
+    })
+}
+
+/// `(integer digits, scale)` a dtype occupies when it meets a decimal, or
+/// `None` for dtypes that do not convert to one exactly.
+///
+/// Every integer dtype counts as 19 digits, the width of `int64`, rather than
+/// its own width: integer promotion can widen past both operands (`int8 ∨
+/// uint8 = int16`), so per-width digit counts would make the fold depend on
+/// the order the columns arrive in. `uint64` does not fit 19 digits and goes
+/// with the floats.
+pub const fn decimal_operand_digits(dtype: DType) -> Option<(u8, u8)> {
+    match dtype {
+        DType::Decimal { precision, scale } => Some((precision.saturating_sub(scale), scale)),
+        DType::Bool | DType::BoolNullable => Some((1, 0)),
+        DType::Int8
+        | DType::Int16
+        | DType::Int32
+        | DType::Int64
+        | DType::Int64Nullable
+        | DType::UInt8
+        | DType::UInt16
+        | DType::UInt32 => Some((19, 0)),
+        _ => None,
+    }
+}
+
+/// Promotion for any pair involving a decimal.
+///
+/// Decimals and integers meet in a decimal wide enough for the integer
+/// digits of both and the larger scale, capped at 38 digits like Arrow's
+/// `decimal128`. A float (or `uint64`) turns the result into
+/// `Float64Nullable`: pyarrow answers `double`, and the decimal's missing
+/// value is `pd.NA`, so the nullable float is the dtype that keeps it.
+fn common_decimal_dtype(left: DType, right: DType) -> Result<DType, TypeError> {
+    match (decimal_operand_digits(left), decimal_operand_digits(right)) {
+        (Some((left_digits, left_scale)), Some((right_digits, right_scale))) => {
+            let scale = left_scale.max(right_scale);
+            Ok(DType::Decimal {
+                precision: left_digits
+                    .max(right_digits)
+                    .saturating_add(scale)
+                    .min(DECIMAL128_MAX_PRECISION),
+                scale,
+            })
+        }
+        _ if left.is_floating()
+            || right.is_floating()
+            || left == DType::UInt64
+            || right == DType::UInt64 =>
+        {
+            Ok(DType::Float64Nullable)
+        }
+        _ => Err(TypeError::IncompatibleDtypes { left, right }),
+    }
+}
+
 pub fn infer_dtype(values: &[Scalar]) -> Result<DType, TypeError> {
     let mut current = DType::Null;
     let mut saw_utf8 = false;
@@ -1269,7 +1706,6 @@ pub fn cast_scalar_owned(value: Scalar, target: DType) -> Result<Scalar, TypeErr
     if value.is_missing() {
         return Ok(Scalar::missing_for_dtype(target));
     }
-
     // Note: identity casts (from == target) are handled above, so same-type
     // arms are omitted from the match below.
     match target {
@@ -1281,6 +1717,7 @@ pub fn cast_scalar_owned(value: Scalar, target: DType) -> Result<Scalar, TypeErr
             // 0.0 and -0.0 -> False; every other value, INCLUDING NaN, -> True
             // (NaN != 0.0 is true), matching numpy/pandas truthiness.
             Scalar::Float64(v) => Ok(Scalar::Bool(*v != 0.0)),
+            Scalar::Decimal(v) => Ok(Scalar::Bool(v.value != 0)),
             _ => Err(TypeError::InvalidCast { from, to: target }),
         },
         DType::BoolNullable => match &value {
@@ -1295,6 +1732,8 @@ pub fn cast_scalar_owned(value: Scalar, target: DType) -> Result<Scalar, TypeErr
             Scalar::Float64(v) if *v == 0.0 => Ok(Scalar::Bool(false)),
             Scalar::Float64(v) if *v == 1.0 => Ok(Scalar::Bool(true)),
             Scalar::Float64(v) => Err(TypeError::InvalidBoolFloat { value: *v }),
+            Scalar::Decimal(v) if *v == Decimal::new(0, 0) => Ok(Scalar::Bool(false)),
+            Scalar::Decimal(v) if *v == Decimal::new(1, 0) => Ok(Scalar::Bool(true)),
             _ => Err(TypeError::InvalidCast { from, to: target }),
         },
         DType::Int64 | DType::Int64Nullable => match &value {
@@ -1330,6 +1769,15 @@ pub fn cast_scalar_owned(value: Scalar, target: DType) -> Result<Scalar, TypeErr
                 }
                 Err(TypeError::InvalidCast { from, to: target })
             }
+            // Truncates toward zero like the float arm above.
+            Scalar::Decimal(v) => {
+                v.trunc_to_i64()
+                    .map(Scalar::Int64)
+                    .ok_or_else(|| TypeError::ValueOutOfRange {
+                        value: v.to_string(),
+                        dtype: target,
+                    })
+            }
             _ => Err(TypeError::InvalidCast { from, to: target }),
         },
         // Both float flavours accept the same source values; they differ only in
@@ -1342,6 +1790,7 @@ pub fn cast_scalar_owned(value: Scalar, target: DType) -> Result<Scalar, TypeErr
                 .parse::<f64>()
                 .map(Scalar::Float64)
                 .map_err(|_| TypeError::InvalidCast { from, to: target }),
+            Scalar::Decimal(v) => Ok(Scalar::Float64(v.to_f64())),
             _ => Err(TypeError::InvalidCast { from, to: target }),
         },
         DType::Utf8 => Ok(Scalar::Utf8(scalar_to_string_for_astype(value))),
@@ -1391,7 +1840,111 @@ pub fn cast_scalar_owned(value: Scalar, target: DType) -> Result<Scalar, TypeErr
                 .map_err(|_| TypeError::InvalidCast { from, to: target }),
             _ => Err(TypeError::InvalidCast { from, to: target }),
         },
-        DType::Sparse => Err(TypeError::InvalidCast { from, to: target }),
+        // Nested values only cast to themselves (handled above) and to strings.
+        DType::Sparse | DType::List | DType::Struct => {
+            Err(TypeError::InvalidCast { from, to: target })
+        }
+        DType::Int8
+        | DType::Int16
+        | DType::Int32
+        | DType::UInt8
+        | DType::UInt16
+        | DType::UInt32
+        | DType::UInt64
+        | DType::Float32 => cast_to_narrow_numeric(value, from, target),
+        DType::Decimal { precision, scale } => cast_to_decimal(value, from, precision, scale),
+    }
+}
+
+/// Cast a present value into `decimal128(precision, scale)`.
+///
+/// Integers, decimals and strings convert exactly: a value that would need
+/// rounding to fit `scale` raises `LossyDecimalRescale`, as pyarrow's safe
+/// cast does. Floats are inexact to begin with and round half-to-even at
+/// `scale`, so `0.1` lands on `0.10` rather than failing on its binary
+/// expansion. A value with more digits than `precision` is out of range.
+fn cast_to_decimal(
+    value: Scalar,
+    from: DType,
+    precision: u8,
+    scale: u8,
+) -> Result<Scalar, TypeError> {
+    let target = DType::Decimal { precision, scale };
+    let lossy = |decimal: Decimal| TypeError::LossyDecimalRescale {
+        value: decimal.to_string(),
+        scale,
+    };
+    let out_of_range = |value: String| TypeError::ValueOutOfRange {
+        value,
+        dtype: target,
+    };
+    let exact = |decimal: Decimal| {
+        decimal.rescale(scale).ok_or_else(|| {
+            if decimal.round_to_scale(scale).is_some() {
+                lossy(decimal)
+            } else {
+                out_of_range(decimal.to_string())
+            }
+        })
+    };
+    let decimal = match &value {
+        Scalar::Bool(v) => exact(Decimal::new(i128::from(*v), 0))?,
+        Scalar::Int64(v) => exact(Decimal::new(i128::from(*v), 0))?,
+        Scalar::Decimal(v) => exact(*v)?,
+        Scalar::Float64(v) if v.is_finite() => {
+            Decimal::from_f64(*v, scale).ok_or_else(|| out_of_range(v.to_string()))?
+        }
+        Scalar::Float64(v) => return Err(out_of_range(v.to_string())),
+        Scalar::Utf8(text) => {
+            exact(
+                Decimal::parse(text).ok_or_else(|| TypeError::ValueNotParseable {
+                    value: text.clone(),
+                    target: format!("decimal128({precision}, {scale})"),
+                })?,
+            )?
+        }
+        _ => return Err(TypeError::InvalidCast { from, to: target }),
+    };
+    if decimal.digits() > precision {
+        return Err(out_of_range(decimal.to_string()));
+    }
+    Ok(Scalar::Decimal(decimal))
+}
+
+/// Cast a present value into a sized numpy dtype.
+///
+/// The value goes through the 64-bit cast first (so truncation, string parsing
+/// and the temporal refusals are shared with `Int64Nullable`/`Float64`) and is
+/// then range-checked. Out-of-range values raise rather than wrap: that is the
+/// pandas CONSTRUCTOR's rule (`pd.Series([300], dtype="int8")` raises
+/// `OverflowError`), and the constructor and `astype` share this cast. numpy's
+/// `astype` would silently wrap 300 to 44, which no caller here wants.
+fn cast_to_narrow_numeric(value: Scalar, from: DType, target: DType) -> Result<Scalar, TypeError> {
+    let wide = if target.is_floating() {
+        DType::Float64
+    } else {
+        DType::Int64Nullable
+    };
+    let widened = cast_scalar_owned(value, wide).map_err(|err| match err {
+        TypeError::InvalidCast { .. } => TypeError::InvalidCast { from, to: target },
+        other => other,
+    })?;
+    match (widened, target.integer_range()) {
+        (Scalar::Int64(v), Some((lo, hi))) if v < lo || v > hi => Err(TypeError::ValueOutOfRange {
+            value: v.to_string(),
+            dtype: target,
+        }),
+        (Scalar::Float64(v), None) => {
+            let narrowed = v as f32;
+            if v.is_finite() && narrowed.is_infinite() {
+                return Err(TypeError::ValueOutOfRange {
+                    value: v.to_string(),
+                    dtype: target,
+                });
+            }
+            Ok(Scalar::Float64(f64::from(narrowed)))
+        }
+        (other, _) => Ok(other),
     }
 }
 
@@ -1460,6 +2013,8 @@ fn scalar_to_string_for_astype(value: Scalar) -> String {
         Scalar::Period(p) if p.ordinal == i64::MIN => "NaT".to_owned(),
         Scalar::Period(p) => p.calendar_string(),
         Scalar::Interval(v) => v.to_string(),
+        Scalar::Decimal(v) => v.to_string(),
+        nested @ (Scalar::List(_) | Scalar::Struct(_)) => nested.to_string(),
     }
 }
 
@@ -4461,10 +5016,48 @@ fn timedelta_ns_to_scalar(ns: i128) -> Scalar {
     Scalar::Timedelta64(ns as i64)
 }
 
+/// Exact sum, count and widest scale of a uniformly-decimal input.
+///
+/// `None` when some present value is not a decimal (the float path then
+/// takes over, as for timedeltas) or when there is no decimal at all. The
+/// inner `None` reports an `i128` overflow, which has no exact answer.
+fn collect_decimal(values: &[Scalar]) -> Option<Option<(Decimal, usize)>> {
+    let mut sum: Option<Decimal> = None;
+    let mut count = 0usize;
+    let mut overflowed = false;
+    for v in values {
+        match v {
+            Scalar::Decimal(d) => {
+                count += 1;
+                sum = match sum {
+                    None => Some(*d),
+                    Some(acc) => match acc.checked_add(*d) {
+                        Some(next) => Some(next),
+                        None => {
+                            overflowed = true;
+                            Some(acc)
+                        }
+                    },
+                };
+            }
+            _ if v.is_missing() => continue,
+            _ => return None,
+        }
+    }
+    let sum = sum?;
+    Some((!overflowed).then_some((sum, count)))
+}
+
 pub fn nansum(values: &[Scalar]) -> Scalar {
     if let Some((sum, _)) = collect_timedelta_ns(values) {
         return timedelta_ns_to_scalar(sum);
     }
+    // Decimal sums stay exact: 0.1 + 0.2 is 0.3, not 0.30000000000000004.
+    if let Some(total) = collect_decimal(values) {
+        return total.map_or(Scalar::Null(NullKind::Null), |(sum, _)| {
+            Scalar::Decimal(sum)
+        });
+    }
     // Fused single-pass fold: filter missing / non-f64-coercible and accumulate
     // in one scan, avoiding the intermediate `collect_finite` Vec<f64> and its
     // second pass. Bit-identical to `collect_finite(..).iter().sum()`: same
@@ -4560,6 +5153,14 @@ pub fn nanmean(values: &[Scalar]) -> Scalar {
         }
         return Scalar::Datetime64(mean as i64);
     }
+    // Decimal mean: exact sum, one half-to-even rounding back to its scale.
+    if let Some(total) = collect_decimal(values) {
+        let count = total.and_then(|(sum, count)| {
+            let count = Decimal::new(i128::try_from(count).ok()?, 0);
+            sum.checked_div(count, sum.scale)
+        });
+        return count.map_or(Scalar::Null(NullKind::Null), Scalar::Decimal);
+    }
     // Fused single-pass fold (see `nansum`): accumulate sum + count of finite
     // values in one scan. Bit-identical to the prior `collect_finite` two-pass:
     // count == nums.len(), sum folds the same values in the same order.
@@ -4711,6 +5312,13 @@ pub fn nanmin(values: &[Scalar]) -> Scalar {
                     min = Some(v)
                 }
             }
+            // Decimals compare exactly; the f64 fallback would tie values
+            // that differ past the 16th digit.
+            (Some(Scalar::Decimal(a)), Scalar::Decimal(b)) => {
+                if b < a {
+                    min = Some(v)
+                }
+            }
             (Some(a), b) => match (a.to_f64(), b.to_f64()) {
                 (Ok(af), Ok(bf)) if bf < af => min = Some(v),
                 (Ok(_), Ok(_)) => {}
@@ -4785,6 +5393,12 @@ pub fn nanmax(values: &[Scalar]) -> Scalar {
                     max = Some(v)
                 }
             }
+            // Exact decimal ordering — see `nanmin`.
+            (Some(Scalar::Decimal(a)), Scalar::Decimal(b)) => {
+                if b > a {
+                    max = Some(v)
+                }
+            }
             (Some(a), b) => match (a.to_f64(), b.to_f64()) {
                 (Ok(af), Ok(bf)) if bf > af => max = Some(v),
                 (Ok(_), Ok(_)) => {}
@@ -5799,6 +6413,8 @@ pub fn nannunique(values: &[Scalar]) -> Scalar {
         Datetime64(i64),
         Period(i64, PeriodFreq),
         Interval(u64, u64, IntervalClosed),
+        Decimal(i128, u8),
+        Nested(String),
     }
 
     let mut seen = FxHashSet::default();
@@ -5822,6 +6438,14 @@ pub fn nannunique(values: &[Scalar]) -> Scalar {
                 normalized_float_bits(v.right),
                 v.closed,
             ),
+            Scalar::Decimal(v) => {
+                let v = v.normalized();
+                ScalarKey::Decimal(v.value, v.scale)
+            }
+            // Debug keeps element dtypes apart (`[1]` vs `[1.0]`).
+            nested @ (Scalar::List(_) | Scalar::Struct(_)) => {
+                ScalarKey::Nested(format!("{nested:?}"))
+            }
             Scalar::Null(_) => continue,
         };
         seen.insert(key);
@@ -6515,10 +7139,7 @@ impl Period {
                 // Euclidean, so a pre-epoch ordinal keeps a remainder in 0..5:
                 // ordinal -1 is 1969-12-31, not a negative weekday index.
                 let offset = WEEKDAY_OFFSET[ord.rem_euclid(5) as usize];
-                let days = ord
-                    .div_euclid(5)
-                    .saturating_mul(7)
-                    .saturating_add(offset);
+                let days = ord.div_euclid(5).saturating_mul(7).saturating_add(offset);
                 let (y, m, d) = civil_from_days(days);
                 write!(rendered, "{y:04}-{m:02}-{d:02}")
             }
@@ -6537,10 +7158,7 @@ impl Period {
                 let start = ord.saturating_mul(7).saturating_add(WEEK_ANCHOR_DAY);
                 let (sy, sm, sd) = civil_from_days(start);
                 let (ey, em, ed) = civil_from_days(start.saturating_add(6));
-                write!(
-                    rendered,
-                    "{sy:04}-{sm:02}-{sd:02}/{ey:04}-{em:02}-{ed:02}"
-                )
+                write!(rendered, "{sy:04}-{sm:02}-{sd:02}/{ey:04}-{em:02}-{ed:02}")
             }
             PeriodFreq::Hourly => {
                 let (y, m, d) = civil_from_days(ord.div_euclid(24));
@@ -6693,6 +7311,310 @@ pub fn period_range(start: Period, periods: usize) -> Vec<Period> {
     (0..periods).map(|i| start.shift(i as i64)).collect()
 }
 
+// ── Decimal128 ──────────────────────────────────────────────────────────
+//
+// Fixed-point values for columns that cannot tolerate binary float rounding
+// (ledgers, prices, rates). Mirrors pyarrow's `decimal128(precision, scale)`
+// as surfaced by pandas through `pd.ArrowDtype`: an i128 unscaled value and a
+// per-column scale, at most 38 significant digits. Arithmetic is exact and
+// checked; the one operation that must round (division) rounds half-to-even,
+// which is Python's `decimal` default.
+
+/// Largest precision a `decimal128` column can declare.
+pub const DECIMAL128_MAX_PRECISION: u8 = 38;
+
+/// Parse a decimal dtype spelling: `decimal128(p, s)` (pyarrow's name, with
+/// or without the pandas `[pyarrow]` suffix) or `decimal(p, s)`. `None` for
+/// anything else, including a precision outside `1..=38` or a scale above it.
+#[must_use]
+pub fn parse_decimal_dtype(spec: &str) -> Option<DType> {
+    let spec = spec.trim();
+    let spec = spec.strip_suffix("[pyarrow]").unwrap_or(spec);
+    let args = spec
+        .strip_prefix("decimal128(")
+        .or_else(|| spec.strip_prefix("decimal("))?
+        .strip_suffix(')')?;
+    let (precision, scale) = args.split_once(',')?;
+    let precision: u8 = precision.trim().parse().ok()?;
+    let scale: u8 = scale.trim().parse().ok()?;
+    ((1..=DECIMAL128_MAX_PRECISION).contains(&precision) && scale <= precision)
+        .then_some(DType::Decimal { precision, scale })
+}
+
+/// A fixed-point decimal: `value * 10^-scale`.
+///
+/// Equality and ordering are numeric, so `1.5` and `1.50` compare equal even
+/// though their scales differ.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Decimal {
+    /// Unscaled integer value.
+    pub value: i128,
+    /// Digits after the decimal point.
+    pub scale: u8,
+}
+
+/// `10^exp`, or `None` past `i128`.
+fn pow10(exp: u32) -> Option<i128> {
+    10_i128.checked_pow(exp)
+}
+
+/// `numerator / denominator` rounded half-to-even; `None` on a zero
+/// denominator or overflow.
+fn div_round_half_even(numerator: i128, denominator: i128) -> Option<i128> {
+    if denominator == 0 {
+        return None;
+    }
+    let quotient = numerator.checked_div(denominator)?;
+    let remainder = numerator.checked_rem(denominator)?;
+    if remainder == 0 {
+        return Some(quotient);
+    }
+    let twice = remainder.unsigned_abs().checked_mul(2)?;
+    let divisor = denominator.unsigned_abs();
+    let away = twice > divisor || (twice == divisor && quotient % 2 != 0);
+    if !away {
+        return Some(quotient);
+    }
+    if (numerator < 0) == (denominator < 0) {
+        quotient.checked_add(1)
+    } else {
+        quotient.checked_sub(1)
+    }
+}
+
+impl Decimal {
+    #[must_use]
+    pub const fn new(value: i128, scale: u8) -> Self {
+        Self { value, scale }
+    }
+
+    /// Parse a decimal literal: optional sign, digits with an optional
+    /// fractional part, and an optional `e`/`E` exponent. The scale is the
+    /// number of written fractional digits (less the exponent, floored at 0),
+    /// so `"1.50"` keeps scale 2. Surrounding whitespace is ignored.
+    #[must_use]
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+        let (mantissa, exponent) = match text.find(['e', 'E']) {
+            Some(at) => (&text[..at], text[at + 1..].parse::<i32>().ok()?),
+            None => (text, 0),
+        };
+        let (negative, digits) = match mantissa.as_bytes().first()? {
+            b'-' => (true, &mantissa[1..]),
+            b'+' => (false, &mantissa[1..]),
+            _ => (false, mantissa),
+        };
+        let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+        if whole.is_empty() && fraction.is_empty() {
+            return None;
+        }
+        let mut value: i128 = 0;
+        for byte in whole.bytes().chain(fraction.bytes()) {
+            if !byte.is_ascii_digit() {
+                return None;
+            }
+            value = value
+                .checked_mul(10)?
+                .checked_add(i128::from(byte - b'0'))?;
+        }
+        if negative {
+            value = -value;
+        }
+        let scale = i32::try_from(fraction.len()).ok()? - exponent;
+        if scale >= 0 {
+            let scale = u8::try_from(scale).ok()?;
+            (scale <= DECIMAL128_MAX_PRECISION).then_some(Self { value, scale })
+        } else {
+            let value = value.checked_mul(pow10(scale.unsigned_abs())?)?;
+            Some(Self { value, scale: 0 })
+        }
+    }
+
+    /// Number of significant digits in the unscaled value (`0` has none).
+    #[must_use]
+    pub fn digits(self) -> u8 {
+        match self.value.unsigned_abs().checked_ilog10() {
+            Some(log) => (log + 1) as u8,
+            None => 0,
+        }
+    }
+
+    /// Smallest `decimal128` precision able to hold this value at its scale.
+    #[must_use]
+    pub fn precision(self) -> u8 {
+        self.digits().max(self.scale).max(1)
+    }
+
+    /// The same number at `scale`, or `None` if that would drop a non-zero
+    /// digit or overflow.
+    #[must_use]
+    pub fn rescale(self, scale: u8) -> Option<Self> {
+        if scale >= self.scale {
+            let factor = pow10(u32::from(scale - self.scale))?;
+            return Some(Self::new(self.value.checked_mul(factor)?, scale));
+        }
+        let factor = pow10(u32::from(self.scale - scale))?;
+        (self.value % factor == 0).then(|| Self::new(self.value / factor, scale))
+    }
+
+    /// The nearest number at `scale`, ties to even. `None` on overflow.
+    #[must_use]
+    pub fn round_to_scale(self, scale: u8) -> Option<Self> {
+        if scale >= self.scale {
+            return self.rescale(scale);
+        }
+        let factor = pow10(u32::from(self.scale - scale))?;
+        Some(Self::new(div_round_half_even(self.value, factor)?, scale))
+    }
+
+    /// This number with trailing fractional zeros removed, so equal values
+    /// share one representation.
+    #[must_use]
+    pub fn normalized(self) -> Self {
+        let mut out = self;
+        while out.scale > 0 && out.value % 10 == 0 {
+            out.value /= 10;
+            out.scale -= 1;
+        }
+        out
+    }
+
+    /// Round a float to `scale` digits. The float is read through its
+    /// shortest round-trip text, so `0.1` means one tenth rather than its
+    /// binary expansion; ties then go to even. `None` for non-finite input
+    /// or overflow.
+    #[must_use]
+    pub fn from_f64(value: f64, scale: u8) -> Option<Self> {
+        if !value.is_finite() {
+            return None;
+        }
+        Self::parse(&value.to_string())?.round_to_scale(scale)
+    }
+
+    /// Nearest `f64`.
+    #[must_use]
+    pub fn to_f64(self) -> f64 {
+        self.to_string().parse().unwrap_or(f64::NAN)
+    }
+
+    /// Integer part, truncated toward zero; `None` outside `i64`.
+    #[must_use]
+    pub fn trunc_to_i64(self) -> Option<i64> {
+        let whole = self.value / pow10(u32::from(self.scale))?;
+        i64::try_from(whole).ok()
+    }
+
+    /// Both operands at the larger of the two scales.
+    fn aligned(self, rhs: Self) -> Option<(i128, i128, u8)> {
+        let scale = self.scale.max(rhs.scale);
+        Some((self.rescale(scale)?.value, rhs.rescale(scale)?.value, scale))
+    }
+
+    /// Exact sum at the larger scale.
+    #[must_use]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let (left, right, scale) = self.aligned(rhs)?;
+        Some(Self::new(left.checked_add(right)?, scale))
+    }
+
+    /// Exact difference at the larger scale.
+    #[must_use]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let (left, right, scale) = self.aligned(rhs)?;
+        Some(Self::new(left.checked_sub(right)?, scale))
+    }
+
+    /// Exact product; its scale is the sum of the operand scales.
+    #[must_use]
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let scale = self.scale.checked_add(rhs.scale)?;
+        if scale > DECIMAL128_MAX_PRECISION {
+            return None;
+        }
+        Some(Self::new(self.value.checked_mul(rhs.value)?, scale))
+    }
+
+    /// Quotient at `scale`, rounded half-to-even. `None` when dividing by
+    /// zero or on overflow.
+    #[must_use]
+    pub fn checked_div(self, rhs: Self, scale: u8) -> Option<Self> {
+        if rhs.value == 0 {
+            return None;
+        }
+        // value / 10^s1 / (rhs / 10^s2) at 10^-scale is
+        // value * 10^(scale + s2 - s1) / rhs.
+        let shift = i32::from(scale) + i32::from(rhs.scale) - i32::from(self.scale);
+        let (numerator, denominator) = if shift >= 0 {
+            (
+                self.value.checked_mul(pow10(shift.unsigned_abs())?)?,
+                rhs.value,
+            )
+        } else {
+            (
+                self.value,
+                rhs.value.checked_mul(pow10(shift.unsigned_abs())?)?,
+            )
+        };
+        Some(Self::new(
+            div_round_half_even(numerator, denominator)?,
+            scale,
+        ))
+    }
+}
+
+impl PartialEq for Decimal {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == std::cmp::Ordering::Equal
+    }
+}
+
+impl Eq for Decimal {}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Decimal {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        if let Some((left, right, _)) = self.aligned(*other) {
+            return left.cmp(&right);
+        }
+        // Aligning overflowed: compare the integer parts, then the fractions
+        // (both below one unit, so they always align).
+        let split = |d: Self| {
+            let unit = pow10(u32::from(d.scale)).unwrap_or(i128::MAX);
+            (
+                d.value.div_euclid(unit),
+                Self::new(d.value.rem_euclid(unit), d.scale),
+            )
+        };
+        let (left_whole, left_fraction) = split(*self);
+        let (right_whole, right_fraction) = split(*other);
+        left_whole
+            .cmp(&right_whole)
+            .then_with(|| match left_fraction.aligned(right_fraction) {
+                Some((left, right, _)) => left.cmp(&right),
+                None => std::cmp::Ordering::Equal,
+            })
+    }
+}
+
+impl std::fmt::Display for Decimal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let digits = self.value.unsigned_abs().to_string();
+        let sign = if self.value < 0 { "-" } else { "" };
+        let scale = usize::from(self.scale);
+        if scale == 0 {
+            return write!(f, "{sign}{digits}");
+        }
+        let padded = format!("{digits:0>width$}", width = scale + 1);
+        let (whole, fraction) = padded.split_at(padded.len() - scale);
+        write!(f, "{sign}{whole}.{fraction}")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
@@ -6985,7 +7907,7 @@ mod tests {
     /// disagree on dtype.
     #[test]
     fn common_dtype_lattice_axioms_be314() {
-        const ALL: [DType; 14] = [
+        const ALL: [DType; 26] = [
             DType::Null,
             DType::Bool,
             DType::BoolNullable,
@@ -6995,6 +7917,22 @@ mod tests {
             // br-frankenpandas-qkqfb: a new variant is only safe once the
             // commutativity and associativity sweeps below cover it.
             DType::Float64Nullable,
+            DType::Int8,
+            DType::Int16,
+            DType::Int32,
+            DType::UInt8,
+            DType::UInt16,
+            DType::UInt32,
+            DType::UInt64,
+            DType::Float32,
+            DType::Decimal {
+                precision: 10,
+                scale: 2,
+            },
+            DType::Decimal {
+                precision: 38,
+                scale: 6,
+            },
             DType::Utf8,
             DType::Categorical,
             DType::Timedelta64,
@@ -7002,6 +7940,8 @@ mod tests {
             DType::Period,
             DType::Interval,
             DType::Sparse,
+            DType::List,
+            DType::Struct,
         ];
 
         for &a in &ALL {
@@ -7122,7 +8062,7 @@ mod tests {
     /// stays missing.
     #[test]
     fn missing_for_dtype_always_missing_1ews0() {
-        const ALL: [DType; 14] = [
+        const ALL: [DType; 26] = [
             DType::Null,
             DType::Bool,
             DType::BoolNullable,
@@ -7130,6 +8070,22 @@ mod tests {
             DType::Int64Nullable,
             DType::Float64,
             DType::Float64Nullable,
+            DType::Int8,
+            DType::Int16,
+            DType::Int32,
+            DType::UInt8,
+            DType::UInt16,
+            DType::UInt32,
+            DType::UInt64,
+            DType::Float32,
+            DType::Decimal {
+                precision: 10,
+                scale: 2,
+            },
+            DType::Decimal {
+                precision: 38,
+                scale: 6,
+            },
             DType::Utf8,
             DType::Categorical,
             DType::Timedelta64,
@@ -7137,6 +8093,8 @@ mod tests {
             DType::Period,
             DType::Interval,
             DType::Sparse,
+            DType::List,
+            DType::Struct,
         ];
         for &dt in &ALL {
             let m = Scalar::missing_for_dtype(dt);
@@ -7193,6 +8151,237 @@ mod tests {
         }
     }
 
+    #[test]
+    fn narrow_numeric_dtypes_promote_like_numpy_and_range_check_casts() {
+        use super::{TypeError, cast_scalar};
+        use DType::{
+            Bool, Float32, Float64, Float64Nullable, Int8, Int16, Int32, Int64, Int64Nullable,
+            UInt8, UInt16, UInt32, UInt64,
+        };
+
+        assert_eq!(Int8.name(), "int8");
+        assert_eq!(UInt64.kind(), 'u');
+        assert_eq!(Float32.itemsize(), 4);
+        assert_eq!(UInt16.str_repr(), "<u2");
+        assert!(UInt32.is_unsigned_integer() && !UInt32.is_signed_integer());
+        assert!(Float32.is_floating() && Float32.is_numeric());
+        assert_eq!(
+            serde_json::to_string(&UInt8).unwrap(),
+            "\"uint8\"",
+            "serde spelling is the numpy name"
+        );
+
+        for (a, b, want) in [
+            (Int8, Int16, Int16),
+            (UInt8, UInt32, UInt32),
+            (Int8, UInt8, Int16),
+            (Int16, UInt16, Int32),
+            (Int32, UInt32, Int64),
+            (Int64, UInt64, Float64),
+            (Int8, UInt64, Float64),
+            (Bool, UInt8, UInt8),
+            (Int16, Float32, Float32),
+            (Int32, Float32, Float64),
+            (Float32, Float64, Float64),
+            (Int8, Int64Nullable, Int64Nullable),
+            (UInt64, Int64Nullable, Float64Nullable),
+            (Float32, Float64Nullable, Float64Nullable),
+        ] {
+            assert_eq!(common_dtype(a, b), Ok(want), "{a:?} ∨ {b:?}");
+        }
+        assert!(common_dtype(Int8, DType::Utf8).is_err());
+
+        assert_eq!(
+            cast_scalar(&Scalar::Int64(127), Int8),
+            Ok(Scalar::Int64(127))
+        );
+        assert_eq!(
+            cast_scalar(&Scalar::Int64(128), Int8),
+            Err(TypeError::ValueOutOfRange {
+                value: "128".to_owned(),
+                dtype: Int8
+            })
+        );
+        assert!(cast_scalar(&Scalar::Int64(-1), UInt64).is_err());
+        assert_eq!(
+            cast_scalar(&Scalar::Float64(255.9), UInt8),
+            Ok(Scalar::Int64(255))
+        );
+        assert_eq!(
+            cast_scalar(&Scalar::Float64(0.1), Float32),
+            Ok(Scalar::Float64(f64::from(0.1_f32)))
+        );
+        assert!(cast_scalar(&Scalar::Float64(1e300), Float32).is_err());
+        assert_eq!(
+            cast_scalar(&Scalar::Float64(f64::INFINITY), Float32),
+            Ok(Scalar::Float64(f64::INFINITY))
+        );
+        assert!(matches!(
+            cast_scalar(&Scalar::Null(NullKind::Null), Float32),
+            Ok(Scalar::Null(NullKind::NaN))
+        ));
+        assert!(matches!(
+            cast_scalar(&Scalar::Datetime64(0), Int32),
+            Err(TypeError::InvalidCast { to: Int32, .. })
+        ));
+    }
+
+    #[test]
+    fn decimal128_arithmetic_is_exact_and_rounds_half_even() {
+        use super::{
+            Decimal, TypeError, cast_scalar, nanmax, nanmean, nannunique, nansum,
+            parse_decimal_dtype,
+        };
+        let dec = |text: &str| Decimal::parse(text).expect("decimal literal");
+        let money = DType::Decimal {
+            precision: 10,
+            scale: 2,
+        };
+
+        assert_eq!(dec("1.50"), Decimal::new(150, 2));
+        assert_eq!(dec("1.50").to_string(), "1.50");
+        assert_eq!(dec("-0.05").to_string(), "-0.05");
+        assert_eq!(dec("1.5e3"), Decimal::new(1500, 0));
+        assert!(Decimal::parse("1.2.3").is_none());
+        assert_eq!(dec("1.5"), dec("1.50"), "equality is numeric");
+        assert!(dec("1.05") < dec("1.5"));
+        assert_eq!(dec("123.45").precision(), 5);
+
+        assert_eq!(dec("0.1").checked_add(dec("0.2")), Some(dec("0.3")));
+        assert_eq!(dec("1").checked_sub(dec("0.01")), Some(dec("0.99")));
+        assert_eq!(
+            dec("1.5").checked_mul(dec("2.25")).map(|d| d.to_string()),
+            Some("3.375".to_owned())
+        );
+        assert_eq!(dec("1").checked_div(dec("3"), 4), Some(dec("0.3333")));
+        assert_eq!(dec("0.25").checked_div(dec("1"), 1), Some(dec("0.2")));
+        assert_eq!(dec("0.35").checked_div(dec("1"), 1), Some(dec("0.4")));
+        assert_eq!(dec("-0.25").checked_div(dec("1"), 1), Some(dec("-0.2")));
+        assert_eq!(dec("1").checked_div(dec("0"), 2), None);
+        assert_eq!(Decimal::new(i128::MAX, 0).checked_add(dec("1")), None);
+
+        let ledger = [
+            Scalar::Decimal(dec("0.10")),
+            Scalar::Null(NullKind::Null),
+            Scalar::Decimal(dec("0.20")),
+        ];
+        assert_eq!(nansum(&ledger), Scalar::Decimal(dec("0.30")));
+        let thirds = [
+            Scalar::Decimal(dec("1.00")),
+            Scalar::Decimal(dec("2.00")),
+            Scalar::Decimal(dec("2.00")),
+        ];
+        assert_eq!(nanmean(&thirds), Scalar::Decimal(dec("1.67")));
+        assert_eq!(nanmax(&thirds), Scalar::Decimal(dec("2")));
+        assert_eq!(nannunique(&thirds), Scalar::Int64(2));
+
+        assert_eq!(
+            cast_scalar(&Scalar::Float64(0.1), money),
+            Ok(Scalar::Decimal(dec("0.10")))
+        );
+        assert_eq!(
+            cast_scalar(&Scalar::Utf8("1.234".to_owned()), money),
+            Err(TypeError::LossyDecimalRescale {
+                value: "1.234".to_owned(),
+                scale: 2
+            })
+        );
+        assert!(matches!(
+            cast_scalar(&Scalar::Utf8("123456789.5".to_owned()), money),
+            Err(TypeError::ValueOutOfRange { .. })
+        ));
+        assert_eq!(
+            cast_scalar(&Scalar::Decimal(dec("1.99")), DType::Int64),
+            Ok(Scalar::Int64(1))
+        );
+        assert_eq!(
+            cast_scalar(&Scalar::Decimal(dec("0.3")), DType::Float64),
+            Ok(Scalar::Float64(0.3))
+        );
+        assert_eq!(
+            cast_scalar(&Scalar::Decimal(dec("0.30")), DType::Utf8),
+            Ok(Scalar::Utf8("0.30".to_owned()))
+        );
+
+        assert_eq!(money.name(), "decimal128");
+        assert_eq!(
+            parse_decimal_dtype("decimal128(10, 2)[pyarrow]"),
+            Some(money)
+        );
+        assert_eq!(parse_decimal_dtype("decimal(39, 0)"), None);
+        assert_eq!(
+            common_dtype(money, DType::Int64),
+            Ok(DType::Decimal {
+                precision: 21,
+                scale: 2
+            })
+        );
+        assert_eq!(
+            common_dtype(
+                money,
+                DType::Decimal {
+                    precision: 5,
+                    scale: 4
+                }
+            ),
+            Ok(DType::Decimal {
+                precision: 12,
+                scale: 4
+            })
+        );
+        assert_eq!(
+            common_dtype(money, DType::Float64),
+            Ok(DType::Float64Nullable)
+        );
+        assert!(common_dtype(money, DType::Utf8).is_err());
+    }
+
+    #[test]
+    fn list_and_struct_scalars_print_and_order_like_python() {
+        use std::cmp::Ordering;
+
+        use super::TypeError;
+
+        let list = Scalar::List(vec![
+            Scalar::Int64(1),
+            Scalar::Utf8("a".to_owned()),
+            Scalar::Null(NullKind::Null),
+        ]);
+        assert_eq!(list.dtype(), DType::List);
+        assert_eq!(list.to_string(), "[1, 'a', None]");
+        let point = Scalar::Struct(vec![
+            ("x".to_owned(), Scalar::Int64(1)),
+            ("tags".to_owned(), Scalar::List(vec![])),
+        ]);
+        assert_eq!(point.dtype(), DType::Struct);
+        assert_eq!(point.to_string(), "{'x': 1, 'tags': []}");
+
+        let short = Scalar::List(vec![Scalar::Int64(1)]);
+        assert_eq!(short.semantic_cmp(&list), Ordering::Less);
+        assert_eq!(
+            Scalar::List(vec![Scalar::Int64(2)]).semantic_cmp(&list),
+            Ordering::Greater
+        );
+        assert_eq!(Scalar::List(vec![]).to_bool(), Ok(false));
+        assert!(matches!(
+            list.to_f64(),
+            Err(TypeError::NonNumericValue { .. })
+        ));
+        assert!(matches!(
+            cast_scalar(&Scalar::Int64(1), DType::List),
+            Err(TypeError::InvalidCast { .. })
+        ));
+        assert_eq!(
+            cast_scalar(&point, DType::Utf8),
+            Ok(Scalar::Utf8("{'x': 1, 'tags': []}".to_owned()))
+        );
+        assert_eq!(
+            infer_dtype(&[short, Scalar::Null(NullKind::Null)]),
+            Ok(DType::List)
+        );
+        assert!(DType::List.is_nested() && DType::Struct.is_nullable());
+    }
+
     #[test]
     fn cast_scalar_bool_int_roundtrip_6w07b() {
         use super::cast_scalar;
@@ -13550,10 +14739,7 @@ mod tests {
             assert_ne!(rendered, later, "business ordinals must not repeat");
         }
 
-        assert_eq!(
-            Period::new(i64::MIN, PeriodFreq::Weekly).to_string(),
-            "NaT"
-        );
+        assert_eq!(Period::new(i64::MIN, PeriodFreq::Weekly).to_string(), "NaT");
         assert_eq!(
             Period::new(i64::MIN, PeriodFreq::Business).to_string(),
             "NaT"
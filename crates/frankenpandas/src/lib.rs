@@ -16,7 +16,9 @@
 
 // ── Core types ──────────────────────────────────────────────────────────
 
-pub use fp_columnar::{ArithmeticOp, Column, ColumnError, ComparisonOp, ValidityMask};
+pub use fp_columnar::{
+    ArithmeticOp, Column, ColumnError, ComparisonOp, ListAccessor, StructAccessor, ValidityMask,
+};
 // ── Expression engine ───────────────────────────────────────────────────
 pub use fp_expr::{
     DataFrameExprExt, Delta, EvalContext, Expr, ExprError, MaterializedView, SeriesRef, eval_str,
@@ -120,32 +122,73 @@ pub use fp_index::{
 };
 // ── IO functions ────────────────────────────────────────────────────────
 pub use fp_io::{
+    // Avro
+    AvroCodec,
+    AvroWriteOptions,
     // CSV
+    CsvChunkIterator,
     CsvOnBadLines,
     CsvReadOptions,
     CsvWriteOptions,
     DEFAULT_HDF5_KEY,
     // Extension trait
     DataFrameIoExt,
+    // Datasets
+    DatasetFormat,
+    DatasetReadOptions,
+    DatasetWriteOptions,
+    EncodingErrors,
     // Excel
     ExcelReadOptions,
     ExcelWriteOptions,
+    ExcelWriter,
+    // Arrow C Data Interface
+    FFI_ArrowArray,
+    FFI_ArrowArrayStream,
+    FFI_ArrowSchema,
     // HDF5 / HTML
+    HdfFormat,
     HdfReadOptions,
     HdfWriteOptions,
+    HtmlExtractLinks,
     HtmlReadOptions,
     // Error type
     IoError,
     // JSON
+    JsonDateFormat,
+    JsonDateUnit,
+    JsonNormalizeErrors,
+    JsonNormalizeOptions,
     JsonOrient,
+    JsonWriteOptions,
+    JsonlReadOptions,
     // Markdown / LaTeX
     LatexWriteOptions,
     MarkdownWriteOptions,
+    // ORC
+    OrcCompression,
+    OrcWriteOptions,
+    // Pickle (pandas object graph)
+    PandasPickleProtocol,
+    PandasPickleWriteOptions,
+    // Parquet
+    ParquetBatchIterator,
+    ParquetCompression,
+    ParquetReadOptions,
+    ParquetStatistics,
+    ParquetWriteOptions,
     // Pickle
     PickleProtocol,
     PickleReadOptions,
     PickleWriteOptions,
+    // SAS
+    SasChunkIterator,
+    SasFormat,
+    SasReadOptions,
     SeriesIoExt,
+    // SPSS
+    SpssMetadata,
+    SpssReadOptions,
     // SQL
     SqlBackendCaps,
     SqlChunkIterator,
@@ -164,20 +207,39 @@ pub use fp_io::{
     SqlUniqueConstraintSchema,
     SqlWriteOptions,
     // Stata
+    StataChunkIterator,
+    StataDateFormat,
+    StataMetadata,
+    StataReadOptions,
+    StataVersion,
     StataWriteOptions,
+    TextCompression,
+    // Arrow C Data Interface
+    dataframe_from_arrow_c_stream,
+    dataframe_to_arrow_c_stream,
     inspect,
+    json_normalize,
+    json_normalize_str,
     list_sql_foreign_keys,
     list_sql_indexes,
     list_sql_schemas,
     list_sql_tables,
     list_sql_unique_constraints,
     list_sql_views,
+    read_avro,
+    read_avro_bytes,
+    read_avro_bytes_many,
+    read_avro_many,
     read_csv,
+    read_csv_chunks,
+    read_csv_chunks_with_compression,
     read_csv_str,
+    read_csv_with_compression,
     read_csv_with_index_cols,
     read_csv_with_index_cols_path,
     read_csv_with_options,
     read_csv_with_options_path,
+    read_dataset,
     read_excel,
     read_excel_bytes,
     // fd90.243: multi-sheet + index-cols Excel readers (pandas-equivalent
@@ -190,6 +252,7 @@ pub use fp_io::{
     read_excel_with_index_cols,
     // Feather (Arrow IPC)
     read_feather,
+    read_feather_batches,
     read_feather_bytes,
     read_hdf,
     read_hdf_key,
@@ -200,19 +263,35 @@ pub use fp_io::{
     read_ipc_stream_bytes,
     read_json,
     read_json_str,
+    read_json_with_compression,
     // JSONL
     read_jsonl,
     read_jsonl_str,
+    read_jsonl_str_with_options,
+    read_jsonl_with_compression,
+    read_jsonl_with_options,
     // ORC
     read_orc,
     read_orc_bytes,
     // Parquet
     read_parquet,
+    read_parquet_batches,
+    read_parquet_batches_with_options,
     read_parquet_bytes,
+    read_parquet_bytes_with_options,
+    read_parquet_with_options,
     read_pickle,
     read_pickle_bytes,
     read_pickle_bytes_with_options,
     read_pickle_with_options,
+    read_sas,
+    read_sas_bytes,
+    read_sas_chunks,
+    read_sas_with_options,
+    read_spss,
+    read_spss_bytes,
+    read_spss_with_metadata,
+    read_spss_with_options,
     read_sql,
     read_sql_chunks,
     read_sql_chunks_with_index_col,
@@ -243,10 +322,15 @@ pub use fp_io::{
     read_sql_with_options,
     read_stata,
     read_stata_bytes,
+    read_stata_bytes_with_options,
+    read_stata_chunks,
+    read_stata_with_metadata,
+    read_stata_with_options,
     // fd90.264: Series-level Arrow interop (README line 1580 mentions
     // DataFrame ↔ Arrow RecordBatch; these are the Series counterparts).
     series_from_arrow_array,
     series_to_arrow_array,
+    series_to_arrow_ffi,
     sql_backend_caps,
     sql_max_identifier_length,
     sql_max_insert_rows,
@@ -258,9 +342,15 @@ pub use fp_io::{
     sql_table_comment,
     sql_table_schema,
     truncate_sql_table,
+    write_avro,
+    write_avro_bytes,
+    write_avro_bytes_with_options,
+    write_avro_with_options,
     write_csv,
     write_csv_string,
     write_csv_string_with_options,
+    write_csv_with_compression,
+    write_dataset,
     write_excel,
     write_excel_bytes,
     write_excel_bytes_with_options,
@@ -273,8 +363,12 @@ pub use fp_io::{
     write_ipc_stream_bytes,
     write_json,
     write_json_string,
+    write_json_string_with_options,
+    write_json_with_compression,
+    write_json_with_options,
     write_jsonl,
     write_jsonl_string,
+    write_jsonl_with_compression,
     write_latex,
     write_latex_string,
     write_latex_string_with_options,
@@ -285,8 +379,16 @@ pub use fp_io::{
     write_markdown_with_options,
     write_orc,
     write_orc_bytes,
+    write_orc_bytes_with_options,
+    write_orc_with_options,
+    write_pandas_pickle,
+    write_pandas_pickle_bytes,
+    write_pandas_pickle_bytes_with_options,
+    write_pandas_pickle_with_options,
     write_parquet,
     write_parquet_bytes,
+    write_parquet_bytes_with_options,
+    write_parquet_with_options,
     write_pickle,
     write_pickle_bytes,
     write_pickle_bytes_with_options,
@@ -339,6 +441,8 @@ pub use fp_types::{
 // scalar variants. Users typically interact via Scalar::Timedelta64(nanos) etc., but
 // the helper types are needed for richer parsing / manipulation.
 pub use fp_types::{
+    DECIMAL128_MAX_PRECISION,
+    Decimal,
     Interval,
     IntervalClosed,
     Period,
@@ -350,6 +454,7 @@ pub use fp_types::{
     // fd90.271: pandas pd.interval_range equivalents (Vec<Interval> generators).
     interval_range_by_periods,
     interval_range_by_step,
+    parse_decimal_dtype,
     period_range,
 };
 // NanOps: null-skipping aggregation primitives (matches README's NanOps section).
@@ -370,6 +475,10 @@ pub use fp_types::{
 // backend can disable `sql-sqlite` and avoid the rusqlite dep entirely.
 #[cfg(feature = "sql-sqlite")]
 pub use rusqlite;
+// The PostgreSQL wire-protocol backend only exists under `sql-postgresql`,
+// so its connection types are re-exported behind the same gate.
+#[cfg(feature = "sql-postgresql")]
+pub use fp_io::{PostgresConfig, PostgresConnection};
 
 // ── Prelude ─────────────────────────────────────────────────────────────
 
@@ -398,6 +507,7 @@ pub mod prelude {
         ArithmeticOp,
         // Join (types + functions, matches README Recipes + Merge: Advanced Options)
         AsofDirection,
+        AvroWriteOptions,
         CategoricalAccessor,
         CategoricalIndex,
         CategoricalMetadata,
@@ -410,6 +520,7 @@ pub mod prelude {
         // fd90.221: expose the types reachable via EvidenceLedger.records().
         CompatibilityIssue,
         ConcatJoin,
+        CsvChunkIterator,
         CsvOnBadLines,
         CsvReadOptions,
         CsvWriteOptions,
@@ -434,6 +545,8 @@ pub mod prelude {
         DataFrameMergeExt,
         DataFrameResample,
         DataFrameRolling,
+        DatasetReadOptions,
+        DatasetWriteOptions,
         // fd90.261: pandas-parity date/timedelta range constructors.
         DateOffset,
         // fd90.16: error types paired with the date/timedelta range
@@ -447,11 +560,13 @@ pub mod prelude {
         DecisionRecord,
         DropNaHow,
         DuplicateKeep,
+        EncodingErrors,
         EvidenceLedger,
         EvidenceTerm,
         Ewm,
         ExcelReadOptions,
         ExcelWriteOptions,
+        ExcelWriter,
         Expanding,
         ExprError,
         FrameError,
@@ -461,6 +576,7 @@ pub mod prelude {
         GroupByOptions,
         GroupByResample,
         GroupByRolling,
+        HdfFormat,
         HdfReadOptions,
         HdfWriteOptions,
         Index,
@@ -479,7 +595,13 @@ pub mod prelude {
         JoinExecutionOptions,
         JoinType,
         JoinedSeries,
+        JsonDateFormat,
+        JsonDateUnit,
+        JsonNormalizeErrors,
+        JsonNormalizeOptions,
         JsonOrient,
+        JsonWriteOptions,
+        JsonlReadOptions,
         LatexWriteOptions,
         MarkdownWriteOptions,
         MergeAsofOptions,
@@ -489,6 +611,9 @@ pub mod prelude {
         MultiIndex,
         MultiIndexOrIndex,
         NullKind,
+        OrcWriteOptions,
+        ParquetReadOptions,
+        ParquetWriteOptions,
         Period,
         PeriodFreq,
         PeriodIndex,
@@ -497,6 +622,7 @@ pub mod prelude {
         Rolling,
         RuntimeMode,
         RuntimePolicy,
+        SasReadOptions,
         Scalar,
         Series,
         SeriesGroupBy,
@@ -507,6 +633,7 @@ pub mod prelude {
         // and the Scalar::Sparse workflow. Without this users couldn't
         // name the dtype after calling sparse().to_dense() etc.
         SparseDType,
+        SpssReadOptions,
         // SQL contracts (covers the README Quick Start round-trip).
         // fd90.206: also expose the option/inspector/chunked-read surface
         // documented in the IO Format Support table at line 148.
@@ -532,8 +659,11 @@ pub mod prelude {
         SqlTableSchema,
         SqlUniqueConstraintSchema,
         SqlWriteOptions,
+        StataReadOptions,
+        StataVersion,
         StataWriteOptions,
         StringAccessor,
+        TextCompression,
         Timedelta,
         TimedeltaComponents,
         TimedeltaError,
@@ -601,6 +731,8 @@ pub mod prelude {
         isnull,
         join_series,
         join_series_with_options,
+        json_normalize,
+        json_normalize_str,
         // fd90.11: module-level SQL helpers (fd90.21-32). Free-function
         // counterparts to SqlInspector methods — paired surface, same
         // semantics. Promote alongside SqlInspector / inspect for
@@ -648,14 +780,22 @@ pub mod prelude {
         period_range,
         qcut,
         // IO — readers (in-memory + path; covers all 8 documented formats)
+        read_avro,
+        read_avro_bytes,
+        read_avro_bytes_many,
+        read_avro_many,
         read_csv,
+        read_csv_chunks,
+        read_csv_chunks_with_compression,
         read_csv_str,
         // fd90.16: index-cols readers pair with read_csv_with_options
         // for the index_col argument shape pandas exposes.
+        read_csv_with_compression,
         read_csv_with_index_cols,
         read_csv_with_index_cols_path,
         read_csv_with_options,
         read_csv_with_options_path,
+        read_dataset,
         read_excel,
         read_excel_bytes,
         // fd90.243: multi-sheet + index-cols Excel readers.
@@ -666,6 +806,7 @@ pub mod prelude {
         read_excel_sheets_ordered_bytes,
         read_excel_with_index_cols,
         read_feather,
+        read_feather_batches,
         read_feather_bytes,
         read_hdf,
         read_hdf_key,
@@ -673,12 +814,24 @@ pub mod prelude {
         read_ipc_stream_bytes,
         read_json,
         read_json_str,
+        read_json_with_compression,
         read_jsonl,
         read_jsonl_str,
+        read_jsonl_str_with_options,
+        read_jsonl_with_compression,
+        read_jsonl_with_options,
         read_orc,
         read_orc_bytes,
         read_parquet,
+        read_parquet_batches,
+        read_parquet_batches_with_options,
         read_parquet_bytes,
+        read_parquet_bytes_with_options,
+        read_parquet_with_options,
+        read_sas,
+        read_sas_with_options,
+        read_spss,
+        read_spss_with_options,
         read_sql,
         read_sql_chunks,
         // fd90.20: paired producer for SqlIndexedChunkIterator (above).
@@ -696,12 +849,15 @@ pub mod prelude {
         read_sql_with_options,
         read_stata,
         read_stata_bytes,
+        read_stata_chunks,
+        read_stata_with_options,
         // fd90.12: Series ↔ Arrow array interop. README line 1580
         // documents Arrow interop as a public surface; fd90.264 added
         // the Series-level pair. Promote to the prelude alongside the
         // rest of the IO surface.
         series_from_arrow_array,
         series_to_arrow_array,
+        series_to_arrow_ffi,
         sql_backend_caps,
         sql_max_identifier_length,
         sql_max_insert_rows,
@@ -724,9 +880,15 @@ pub mod prelude {
         to_timedelta_with_unit,
         truncate_sql_table,
         // IO — writers (in-memory + path + sql; covers all 8 documented formats)
+        write_avro,
+        write_avro_bytes,
+        write_avro_bytes_with_options,
+        write_avro_with_options,
         write_csv,
         write_csv_string,
         write_csv_string_with_options,
+        write_csv_with_compression,
+        write_dataset,
         write_excel,
         write_excel_bytes,
         write_excel_bytes_with_options,
@@ -739,8 +901,12 @@ pub mod prelude {
         write_ipc_stream_bytes,
         write_json,
         write_json_string,
+        write_json_string_with_options,
+        write_json_with_compression,
+        write_json_with_options,
         write_jsonl,
         write_jsonl_string,
+        write_jsonl_with_compression,
         write_latex,
         write_latex_string,
         write_latex_string_with_options,
@@ -751,8 +917,12 @@ pub mod prelude {
         write_markdown_with_options,
         write_orc,
         write_orc_bytes,
+        write_orc_bytes_with_options,
+        write_orc_with_options,
         write_parquet,
         write_parquet_bytes,
+        write_parquet_bytes_with_options,
+        write_parquet_with_options,
         write_sql,
         // fd90.209: write_sql_with_options pairs with SqlWriteOptions
         // (which is in the prelude as of fd90.206).
@@ -997,6 +1167,7 @@ mod tests {
         let _ = read_excel;
         let _ = read_excel_bytes;
         let _ = read_feather;
+        let _ = read_feather_batches;
         let _ = read_feather_bytes;
         let _ = read_ipc_stream_bytes;
         let _ = read_json;
@@ -1035,9 +1206,39 @@ mod tests {
         // fd90.216: CSV/Excel write options + write_*_with_options now in prelude.
         let _: CsvWriteOptions = CsvWriteOptions::default();
         let _: ExcelWriteOptions = ExcelWriteOptions::default();
+        let _: ExcelWriter = ExcelWriter::new();
         let _ = write_csv_string_with_options;
         let _ = write_excel_with_options;
         let _ = write_excel_bytes_with_options;
+        let _: ParquetWriteOptions = ParquetWriteOptions::default();
+        let _: ParquetReadOptions = ParquetReadOptions::default();
+        let _ = read_parquet_bytes_with_options;
+        let _ = read_parquet_with_options;
+        let _ = read_parquet_batches;
+        let _ = read_parquet_batches_with_options;
+        let _: DatasetReadOptions = DatasetReadOptions::default();
+        let _: DatasetWriteOptions = DatasetWriteOptions::default();
+        let _ = read_dataset;
+        let _ = write_dataset;
+        let _ = write_parquet_bytes_with_options;
+        let _ = write_parquet_with_options;
+        let _: OrcWriteOptions = OrcWriteOptions::default();
+        let _: AvroWriteOptions = AvroWriteOptions::default();
+        let _ = read_avro_many::<&std::path::Path>;
+        let _ = write_avro_bytes_with_options;
+        let _ = write_avro_with_options;
+        let _ = write_orc_bytes_with_options;
+        let _ = write_orc_with_options;
+        let _: SasReadOptions = SasReadOptions::default();
+        let _ = read_sas;
+        let _ = read_sas_with_options;
+        let _: SpssReadOptions = SpssReadOptions::default();
+        let _ = read_spss;
+        let _ = read_spss_with_options;
+        let _: StataReadOptions = StataReadOptions::default();
+        let _: StataVersion = StataVersion::default();
+        let _ = read_stata_chunks;
+        let _ = read_stata_with_options;
 
         // fd90.217: merge_asof options + JoinExecutionOptions in prelude.
         let _: MergeAsofOptions = MergeAsofOptions::default();
@@ -109,6 +109,7 @@ fn readme_quick_start_round_trip_through_sqlite() -> Result<(), Box<dyn std::err
         header: true,
         include_index: false,
         index_label: None,
+        ..CsvWriteOptions::default()
     };
     let csv_via_trait = by_ticker.to_csv_string_with_options(&csv_opts)?;
     assert!(csv_via_trait.contains(';'));
@@ -121,6 +122,7 @@ fn readme_quick_start_round_trip_through_sqlite() -> Result<(), Box<dyn std::err
         index: false,
         index_label: None,
         header: true,
+        ..ExcelWriteOptions::default()
     };
     let _bytes_excel_opts = by_ticker.to_excel_bytes_with_options(&excel_opts)?;
     let plain_table = by_ticker.to_string();